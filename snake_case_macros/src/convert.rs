@@ -0,0 +1,116 @@
+//! A copy of the word splitting in `snake_case`, which we can't depend on
+//! since it depends on us.
+
+/// `MyHTTPServer2` -> `my_http_server2`
+///
+/// Returns `None` for identifiers we can't convert faithfully (non-ASCII, or no letters at all).
+pub(crate) fn to_snake_case(ident: &str) -> Option<String> {
+    let ident = ident.strip_prefix("r#").unwrap_or(ident);
+    if !ident.is_ascii() {
+        return None;
+    }
+    let bytes = ident.as_bytes();
+
+    let mut out = String::with_capacity(ident.len() + 4);
+    let mut pos = 0;
+    loop {
+        while pos < bytes.len() && !bytes[pos].is_ascii_alphanumeric() {
+            pos += 1;
+        }
+        if pos == bytes.len() {
+            break;
+        }
+
+        let start = pos;
+        let mut end = start + 1;
+        while end < bytes.len() {
+            let (prev, cur) = (bytes[end - 1], bytes[end]);
+            if !cur.is_ascii_alphanumeric() {
+                break;
+            }
+            if cur.is_ascii_uppercase() {
+                if prev.is_ascii_lowercase() || prev.is_ascii_digit() {
+                    break; // helloWorld
+                }
+                let next_is_lower = matches!(bytes.get(end + 1), Some(b) if b.is_ascii_lowercase());
+                if prev.is_ascii_uppercase() && next_is_lower {
+                    break; // HTTPServer
+                }
+            }
+            end += 1;
+        }
+        pos = end;
+
+        let word = &ident[start..end];
+        if out.is_empty() {
+            if word.as_bytes()[0].is_ascii_digit() {
+                out.push('_');
+            }
+        } else {
+            out.push('_');
+        }
+        out.push_str(&word.to_ascii_lowercase());
+    }
+
+    if out.is_empty() {
+        None
+    } else {
+        Some(out)
+    }
+}
+
+/// Same as `snake_case::is_snake_case`: ^[_a-z][_a-z0-9]*$
+pub(crate) fn is_snake_case(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    matches!(bytes.first(), Some(b) if *b == b'_' || b.is_ascii_lowercase())
+        && bytes
+            .iter()
+            .all(|b| *b == b'_' || b.is_ascii_lowercase() || b.is_ascii_digit())
+}
+
+/// What is wrong with a string that is not snake_case, e.g. "uppercase 'P' at offset 0".
+pub(crate) fn snake_case_problem(s: &str) -> Option<String> {
+    let (offset, c) = match s
+        .char_indices()
+        .find(|&(i, c)| !(c == '_' || c.is_ascii_lowercase() || (i > 0 && c.is_ascii_digit())))
+    {
+        Some(found) => found,
+        None if s.is_empty() => return Some("empty string".to_owned()),
+        None => return None,
+    };
+    Some(if c.is_ascii_uppercase() {
+        format!("uppercase {:?} at offset {}", c, offset)
+    } else if offset == 0 {
+        format!("invalid start character {:?} at offset 0", c)
+    } else {
+        format!("invalid character {:?} at offset {}", c, offset)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn to_snake_case() {
+        let snake = |s| super::to_snake_case(s).unwrap();
+        assert_eq!(snake("MyHttpServer"), "my_http_server");
+        assert_eq!(snake("HTTPServer"), "http_server");
+        assert_eq!(snake("Vec3d"), "vec3d");
+        assert_eq!(snake("r#Type"), "type");
+        assert_eq!(snake("already_snake"), "already_snake");
+        assert_eq!(super::to_snake_case("Größe"), None);
+        assert_eq!(super::to_snake_case("__"), None);
+    }
+
+    #[test]
+    fn snake_case_problem() {
+        let problem = |s| super::snake_case_problem(s);
+        assert_eq!(problem("hello_world2"), None);
+        assert_eq!(problem("Python").unwrap(), "uppercase 'P' at offset 0");
+        assert_eq!(
+            problem("2d").unwrap(),
+            "invalid start character '2' at offset 0"
+        );
+        assert_eq!(problem("a-b").unwrap(), "invalid character '-' at offset 1");
+        assert_eq!(problem("").unwrap(), "empty string");
+    }
+}
@@ -0,0 +1,488 @@
+//! Derive macros for the [`snake_case`](https://docs.rs/snake_case) crate.
+//!
+//! Use them through `snake_case` with the `"derive"` feature enabled, rather than depending on this crate directly.
+
+mod convert;
+mod list;
+mod map;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned as _, Data, DeriveInput, Fields, LitStr};
+
+/// A `SnakeCaseRef<'static>` from a string literal, checked at compile time.
+///
+/// Usable in `const` contexts. Invalid input is a compile error pointing at the literal,
+/// e.g. ``expected snake_case, found `Python` (uppercase 'P' at offset 0)``.
+///
+/// ```
+/// use snake_case::{snake_case, SnakeCaseRef};
+///
+/// const TABLE: SnakeCaseRef<'static> = snake_case!("user_accounts");
+/// assert_eq!(TABLE, "user_accounts");
+/// ```
+///
+/// ```compile_fail
+/// let table = snake_case::snake_case!("UserAccounts");
+/// ```
+#[proc_macro]
+pub fn snake_case(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    let value = lit.value();
+    if let Some(problem) = convert::snake_case_problem(&value) {
+        return syn::Error::new(
+            lit.span(),
+            format!("expected snake_case, found `{}` ({})", value, problem),
+        )
+        .to_compile_error()
+        .into();
+    }
+    quote! { ::snake_case::SnakeCaseRef::from_str_or_panic(#lit) }.into()
+}
+
+/// An array of `SnakeCaseRef<'static>` from string literals, each checked at compile time,
+/// with duplicates being a compile error too.
+///
+/// Either as an expression, or as a `const` item with the length filled in for you:
+///
+/// ```
+/// use snake_case::{snake_case_list, SnakeCaseRef};
+///
+/// snake_case_list! {
+///     /// All the tables we know about.
+///     pub const TABLES = ["users", "orders", "order_items"];
+/// }
+/// assert_eq!(TABLES.len(), 3);
+///
+/// const COLUMNS: &[SnakeCaseRef<'static>] = &snake_case_list!["id", "created_at"];
+/// assert_eq!(COLUMNS[1], "created_at");
+/// ```
+///
+/// ```compile_fail
+/// snake_case::snake_case_list!["users", "orders", "users"];
+/// ```
+#[proc_macro]
+pub fn snake_case_list(input: TokenStream) -> TokenStream {
+    let list = parse_macro_input!(input as list::SnakeCaseList);
+    match list.expand() {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// A `snake_case::SnakeCaseMap` from `"key" => value` pairs.
+///
+/// The keys are checked, de-duplicated and sorted at compile time.
+/// This means the values are evaluated in key order, not in the order they are written.
+///
+/// ```
+/// use snake_case::{snake_case_map, SnakeCaseMap};
+///
+/// let handlers: SnakeCaseMap<'_, fn() -> &'static str> = snake_case_map! {
+///     "ping" => || "pong",
+///     "get_status" => || "ok",
+/// };
+/// assert_eq!((handlers.get("ping").unwrap())(), "pong");
+/// ```
+///
+/// ```compile_fail
+/// let map = snake_case::snake_case_map! { "a" => 1, "a" => 2 };
+/// ```
+#[proc_macro]
+pub fn snake_case_map(input: TokenStream) -> TokenStream {
+    let map = parse_macro_input!(input as map::SnakeCaseMap);
+    match map.expand() {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Implements `snake_case::SnakeCaseTypeName` for a type,
+/// with the name of the type converted to snake_case at compile time.
+///
+/// ```
+/// use snake_case::SnakeCaseTypeName;
+///
+/// #[derive(SnakeCaseTypeName)]
+/// struct MyHttpServer;
+///
+/// assert_eq!(MyHttpServer::TYPE_NAME, "my_http_server");
+/// ```
+#[proc_macro_derive(SnakeCaseTypeName)]
+pub fn derive_snake_case_type_name(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let name = match snake_case_lit(&ident.to_string(), ident.span()) {
+        Ok(name) => name,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    quote! {
+        impl #impl_generics ::snake_case::SnakeCaseTypeName for #ident #ty_generics #where_clause {
+            const TYPE_NAME: ::snake_case::SnakeCaseRef<'static> = #name;
+        }
+    }
+    .into()
+}
+
+/// Implements `snake_case::SnakeCaseFields` for a struct with named fields,
+/// listing the names of all the fields.
+///
+/// Every field name must be valid snake_case, or you get a compile error.
+///
+/// ```
+/// use snake_case::SnakeCaseFields;
+///
+/// #[derive(SnakeCaseFields)]
+/// struct User {
+///     id: u64,
+///     display_name: String,
+/// }
+///
+/// assert_eq!(User::FIELDS, ["id", "display_name"]);
+/// ```
+#[proc_macro_derive(SnakeCaseFields)]
+pub fn derive_snake_case_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match snake_case_fields(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn snake_case_fields(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "SnakeCaseFields can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "SnakeCaseFields can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut names = Vec::new();
+    for field in fields {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let name = field_ident.to_string();
+        let name = name.strip_prefix("r#").unwrap_or(&name);
+        if !convert::is_snake_case(name) {
+            return Err(syn::Error::new(
+                field_ident.span(),
+                format!("Field name {:?} is not valid snake_case", name),
+            ));
+        }
+        names.push(quote! { ::snake_case::SnakeCaseRef::from_str_or_panic(#name) });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::snake_case::SnakeCaseFields for #ident #ty_generics #where_clause {
+            const FIELDS: &'static [::snake_case::SnakeCaseRef<'static>] = &[#(#names),*];
+        }
+    })
+}
+
+/// Adds `fn validate_idents(&self) -> Result<(), snake_case::FieldError>` to a struct with named fields,
+/// checking that every field marked `#[snake_case]` is valid snake_case.
+///
+/// For config structs where changing the field types to `SnakeCase` would be too invasive.
+/// The marked fields can be of any type implementing `AsRef<str>`, like `String` or `&str`.
+///
+/// ```
+/// use snake_case::ValidateSnakeCaseFields;
+///
+/// #[derive(ValidateSnakeCaseFields)]
+/// struct Config {
+///     #[snake_case]
+///     table: String,
+///     description: String,
+/// }
+///
+/// let config = Config {
+///     table: "UserAccounts".to_owned(),
+///     description: "Anything goes".to_owned(),
+/// };
+/// let err = config.validate_idents().unwrap_err();
+/// assert_eq!(err.field, "table");
+/// ```
+///
+/// The fields are checked in declaration order, and the first invalid one is returned.
+#[proc_macro_derive(ValidateSnakeCaseFields, attributes(snake_case))]
+pub fn derive_validate_snake_case_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match validate_snake_case_fields(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn validate_snake_case_fields(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "ValidateSnakeCaseFields can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "ValidateSnakeCaseFields can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut checks = Vec::new();
+    for field in fields {
+        let mut marked = false;
+        for attr in &field.attrs {
+            if attr.path().is_ident("snake_case") {
+                attr.meta.require_path_only()?;
+                marked = true;
+            }
+        }
+        if !marked {
+            continue;
+        }
+
+        let field_ident = field.ident.as_ref().expect("named field");
+        let name = field_ident.to_string();
+        let name = name.strip_prefix("r#").unwrap_or(&name);
+        checks.push(quote! {
+            if let ::core::result::Result::Err(error) = ::snake_case::SnakeStr::try_from_str(
+                ::core::convert::AsRef::<str>::as_ref(&self.#field_ident),
+            ) {
+                return ::core::result::Result::Err(::snake_case::FieldError { field: #name, error });
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Check that all the fields marked `#[snake_case]` are valid snake_case.
+            pub fn validate_idents(&self) -> ::core::result::Result<(), ::snake_case::FieldError> {
+                #(#checks)*
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
+
+/// Implements `snake_case::SnakeCaseVariants` for an enum,
+/// with the names of the variants converted to snake_case at compile time.
+///
+/// Two variants converting to the same name (e.g. `HttpServer` and `HTTPServer`) is a compile error.
+///
+/// ```
+/// use snake_case::SnakeCaseVariants;
+///
+/// #[derive(SnakeCaseVariants)]
+/// enum Event {
+///     KeyDown,
+///     MouseMove { x: f32, y: f32 },
+/// }
+///
+/// assert_eq!(Event::VARIANTS, ["key_down", "mouse_move"]);
+/// assert_eq!(Event::KeyDown.variant_name(), "key_down");
+/// ```
+#[proc_macro_derive(SnakeCaseVariants)]
+pub fn derive_snake_case_variants(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match snake_case_variants(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn snake_case_variants(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "SnakeCaseVariants can only be derived for enums",
+            ))
+        }
+    };
+
+    let mut seen = Vec::<(String, &syn::Ident)>::new();
+    let mut names = Vec::new();
+    let mut arms = Vec::new();
+    for variant in variants {
+        let variant_ident = &variant.ident;
+        let original = variant_ident.to_string();
+        let name = convert::to_snake_case(&original).ok_or_else(|| {
+            syn::Error::new(
+                variant_ident.span(),
+                format!(
+                    "Can't convert {:?} to snake_case: only ASCII identifiers are supported",
+                    original
+                ),
+            )
+        })?;
+        if let Some((_, other)) = seen.iter().find(|(seen_name, _)| *seen_name == name) {
+            return Err(syn::Error::new(
+                variant_ident.span(),
+                format!(
+                    "Both {} and {} become {:?} in snake_case",
+                    other, variant_ident, name
+                ),
+            ));
+        }
+        seen.push((name.clone(), variant_ident));
+
+        let lit = quote! { ::snake_case::SnakeCaseRef::from_str_or_panic(#name) };
+        arms.push(quote! { Self::#variant_ident { .. } => #lit, });
+        names.push(lit);
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::snake_case::SnakeCaseVariants for #ident #ty_generics #where_clause {
+            const VARIANTS: &'static [::snake_case::SnakeCaseRef<'static>] = &[#(#names),*];
+
+            fn variant_name(&self) -> ::snake_case::SnakeCaseRef<'static> {
+                match *self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+/// The boilerplate for a newtype around `SnakeCase`, for domain-specific identifier types.
+///
+/// For `struct TableName(SnakeCase);` this implements:
+/// * `TableName::as_str`
+/// * `TryFrom<&str>` and `FromStr`, with `snake_case::InvalidSnakeCase` as the error
+/// * `Display`, `AsRef<str>` and `Borrow<str>`
+/// * `Serialize` and `Deserialize`, if the `"serde"` feature of `snake_case` is enabled
+///
+/// ```
+/// use snake_case::{SnakeCase, SnakeCaseNewtype};
+/// use std::convert::TryFrom;
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Hash, SnakeCaseNewtype)]
+/// struct TableName(SnakeCase);
+///
+/// let table = TableName::try_from("user_accounts").unwrap();
+/// assert_eq!(table.as_str(), "user_accounts");
+/// assert_eq!(table.to_string(), "user_accounts");
+/// assert!("UserAccounts".parse::<TableName>().is_err());
+/// ```
+///
+/// The field can be any of the owned snake_case types, like `SnakeCase` or `ArcSnakeCase`.
+#[proc_macro_derive(SnakeCaseNewtype)]
+pub fn derive_snake_case_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match snake_case_newtype(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn snake_case_newtype(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let field = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+            _ => return Err(newtype_error(ident)),
+        },
+        _ => return Err(newtype_error(ident)),
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "SnakeCaseNewtype can't be derived for generic types",
+        ));
+    }
+    let ty = &field.ty;
+
+    Ok(quote! {
+        impl #ident {
+            pub fn as_str(&self) -> &str {
+                ::core::convert::AsRef::<str>::as_ref(&self.0)
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for #ident {
+            type Error = ::snake_case::InvalidSnakeCase;
+
+            fn try_from(s: &str) -> ::core::result::Result<Self, Self::Error> {
+                <#ty as ::core::convert::TryFrom<&str>>::try_from(s).map(Self)
+            }
+        }
+
+        impl ::core::str::FromStr for #ident {
+            type Err = ::snake_case::InvalidSnakeCase;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                <#ty as ::core::convert::TryFrom<&str>>::try_from(s).map(Self)
+            }
+        }
+
+        impl ::core::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(self.as_str(), f)
+            }
+        }
+
+        impl ::core::convert::AsRef<str> for #ident {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl ::core::borrow::Borrow<str> for #ident {
+            fn borrow(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        ::snake_case::__newtype_serde!(#ident, #ty);
+    })
+}
+
+fn newtype_error(ident: &syn::Ident) -> syn::Error {
+    syn::Error::new(
+        ident.span(),
+        "SnakeCaseNewtype can only be derived for tuple structs with a single field, like `struct TableName(SnakeCase);`",
+    )
+}
+
+/// The expression for a `SnakeCaseRef<'static>` of the identifier converted to snake_case.
+fn snake_case_lit(ident: &str, span: Span) -> syn::Result<TokenStream2> {
+    let name = convert::to_snake_case(ident).ok_or_else(|| {
+        syn::Error::new(
+            span,
+            format!(
+                "Can't convert {:?} to snake_case: only ASCII identifiers are supported",
+                ident
+            ),
+        )
+    })?;
+    // The conversion always produces valid snake_case, so this never panics:
+    Ok(quote! { ::snake_case::SnakeCaseRef::from_str_or_panic(#name) })
+}
@@ -0,0 +1,109 @@
+//! Parsing and expanding `snake_case_list!`.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    bracketed,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Attribute, Ident, LitStr, Token, Visibility,
+};
+
+use crate::convert;
+
+/// Either `"a", "b"` or `pub const NAMES = ["a", "b"];`
+pub(crate) enum SnakeCaseList {
+    Expr(Vec<LitStr>),
+    Const {
+        attrs: Vec<Attribute>,
+        vis: Visibility,
+        ident: Ident,
+        lits: Vec<LitStr>,
+    },
+}
+
+fn parse_lits(input: ParseStream<'_>) -> syn::Result<Vec<LitStr>> {
+    let lits = Punctuated::<LitStr, Token![,]>::parse_terminated(input)?;
+    Ok(lits.into_iter().collect())
+}
+
+impl Parse for SnakeCaseList {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        if input.is_empty() || input.peek(LitStr) {
+            return parse_lits(input).map(SnakeCaseList::Expr);
+        }
+
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![const]>()?;
+        let ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let content;
+        bracketed!(content in input);
+        let lits = parse_lits(&content)?;
+        if !input.is_empty() {
+            input.parse::<Token![;]>()?;
+        }
+        Ok(SnakeCaseList::Const {
+            attrs,
+            vis,
+            ident,
+            lits,
+        })
+    }
+}
+
+impl SnakeCaseList {
+    pub(crate) fn expand(&self) -> syn::Result<TokenStream2> {
+        let lits = match self {
+            SnakeCaseList::Expr(lits) | SnakeCaseList::Const { lits, .. } => lits,
+        };
+        let names = checked_names(lits)?;
+        let array = quote! { [#(#names),*] };
+
+        Ok(match self {
+            SnakeCaseList::Expr(_) => array,
+            SnakeCaseList::Const {
+                attrs, vis, ident, ..
+            } => {
+                let len = lits.len();
+                quote! {
+                    #(#attrs)*
+                    #vis const #ident: [::snake_case::SnakeCaseRef<'static>; #len] = #array;
+                }
+            }
+        })
+    }
+}
+
+/// Validates every literal and checks for duplicates, reporting all problems at once.
+pub(crate) fn checked_names(lits: &[LitStr]) -> syn::Result<Vec<TokenStream2>> {
+    let mut errors: Option<syn::Error> = None;
+    let mut push_error = |err: syn::Error| match &mut errors {
+        Some(errors) => errors.combine(err),
+        None => errors = Some(err),
+    };
+
+    let mut names = Vec::with_capacity(lits.len());
+    for (i, lit) in lits.iter().enumerate() {
+        let value = lit.value();
+        if let Some(problem) = convert::snake_case_problem(&value) {
+            push_error(syn::Error::new(
+                lit.span(),
+                format!("expected snake_case, found `{}` ({})", value, problem),
+            ));
+        } else if let Some(first) = lits[..i].iter().find(|other| other.value() == value) {
+            push_error(syn::Error::new(
+                lit.span(),
+                format!("duplicate snake_case `{}`", value),
+            ));
+            push_error(syn::Error::new(first.span(), "first listed here"));
+        }
+        names.push(quote! { ::snake_case::SnakeCaseRef::from_str_or_panic(#lit) });
+    }
+
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(names),
+    }
+}
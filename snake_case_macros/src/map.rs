@@ -0,0 +1,64 @@
+//! Parsing and expanding `snake_case_map!`.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Expr, LitStr, Token,
+};
+
+use crate::list::checked_names;
+
+/// `"key" => value`
+struct Entry {
+    key: LitStr,
+    value: Expr,
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let key = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let value = input.parse()?;
+        Ok(Entry { key, value })
+    }
+}
+
+pub(crate) struct SnakeCaseMap {
+    entries: Vec<Entry>,
+}
+
+impl Parse for SnakeCaseMap {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let entries = Punctuated::<Entry, Token![,]>::parse_terminated(input)?;
+        Ok(SnakeCaseMap {
+            entries: entries.into_iter().collect(),
+        })
+    }
+}
+
+impl SnakeCaseMap {
+    pub(crate) fn expand(self) -> syn::Result<TokenStream2> {
+        let keys: Vec<LitStr> = self.entries.iter().map(|entry| entry.key.clone()).collect();
+        let names = checked_names(&keys)?;
+
+        let mut entries: Vec<(String, TokenStream2, Expr)> = self
+            .entries
+            .into_iter()
+            .zip(names)
+            .map(|(entry, name)| (entry.key.value(), name, entry.value))
+            .collect();
+        // The same order as `str::cmp`, which the lookups rely on:
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let entries = entries
+            .into_iter()
+            .map(|(_, name, value)| quote! { (#name, #value) });
+        Ok(quote! {
+            ::snake_case::SnakeCaseMap {
+                __entries: &[#(#entries),*],
+            }
+        })
+    }
+}
@@ -0,0 +1,154 @@
+use snake_case::{SnakeCaseFields, SnakeCaseRef, SnakeCaseTypeName, SnakeCaseVariants};
+
+#[test]
+fn type_name() {
+    #[derive(SnakeCaseTypeName)]
+    struct MyHTTPServer2;
+
+    #[derive(SnakeCaseTypeName)]
+    #[allow(dead_code)]
+    enum Color {
+        Red,
+    }
+
+    #[derive(SnakeCaseTypeName)]
+    #[allow(dead_code)]
+    struct Wrapper<'a, T: Clone>(&'a T);
+
+    assert_eq!(MyHTTPServer2::TYPE_NAME, "my_http_server2");
+    assert_eq!(Color::TYPE_NAME, "color");
+    assert_eq!(<Wrapper<'_, u8>>::TYPE_NAME, "wrapper");
+
+    const NAME: SnakeCaseRef<'static> = MyHTTPServer2::TYPE_NAME;
+    assert_eq!(NAME, "my_http_server2");
+}
+
+#[test]
+fn fields() {
+    #[derive(SnakeCaseFields)]
+    #[allow(dead_code)]
+    struct Row<T> {
+        id: u64,
+        r#type: T,
+        _secret: (),
+    }
+
+    assert_eq!(<Row<()>>::FIELDS, ["id", "type", "_secret"]);
+}
+
+#[test]
+fn variants() {
+    #[derive(SnakeCaseVariants)]
+    #[allow(dead_code)]
+    enum Message<T> {
+        Ping,
+        HTTPRequest(T),
+        ShutDown { reason: String },
+    }
+
+    #[derive(SnakeCaseVariants)]
+    enum Never {}
+
+    assert_eq!(
+        <Message<()>>::VARIANTS,
+        ["ping", "http_request", "shut_down"]
+    );
+    assert_eq!(Message::<()>::Ping.variant_name(), "ping");
+    assert_eq!(Message::HTTPRequest(42).variant_name(), "http_request");
+    assert_eq!(Never::VARIANTS.len(), 0);
+}
+
+#[test]
+fn literal() {
+    const ID: SnakeCaseRef<'static> = snake_case::snake_case!("_private_2");
+    assert_eq!(ID, "_private_2");
+}
+
+#[test]
+fn list() {
+    snake_case::snake_case_list! {
+        const NAMES = ["a", "b_2", "_c"];
+    }
+    let names: [SnakeCaseRef<'static>; 3] = NAMES;
+    assert_eq!(names, ["a", "b_2", "_c"]);
+
+    let empty: [SnakeCaseRef<'static>; 0] = snake_case::snake_case_list![];
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn map() {
+    const MAP: snake_case::SnakeCaseMap<'static, u8> = snake_case::snake_case_map! {
+        "retry_count" => 5,
+        "max_depth" => 3,
+        "_internal" => 1 + 1,
+    };
+    assert_eq!(
+        MAP.keys().collect::<Vec<_>>(),
+        ["_internal", "max_depth", "retry_count"]
+    );
+    assert_eq!(MAP.get("max_depth"), Some(&3));
+    assert_eq!(MAP.get("_internal"), Some(&2));
+    assert_eq!(MAP.get("missing"), None);
+}
+
+#[test]
+fn newtype() {
+    use snake_case::{SnakeCase, SnakeCaseNewtype};
+    use std::{collections::HashSet, convert::TryFrom};
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, SnakeCaseNewtype)]
+    struct TableName(SnakeCase);
+
+    let table = TableName::try_from("users").unwrap();
+    assert_eq!(format!("{}", table), "users");
+    assert_eq!(
+        "Users".parse::<TableName>().unwrap_err(),
+        snake_case::InvalidSnakeCase::UppercaseChar { offset: 0 }
+    );
+
+    assert_eq!(serde_json::to_string(&table).unwrap(), r#""users""#);
+    let parsed: TableName = serde_json::from_str(r#""users""#).unwrap();
+    assert_eq!(parsed, table);
+    assert!(serde_json::from_str::<TableName>(r#""Users""#).is_err());
+
+    let tables: HashSet<TableName> = std::iter::once(table).collect();
+    assert!(tables.contains("users"));
+}
+
+#[test]
+fn validate_fields() {
+    use snake_case::{FieldError, InvalidSnakeCase, ValidateSnakeCaseFields};
+
+    #[derive(ValidateSnakeCaseFields)]
+    #[allow(dead_code)]
+    struct Config<'a> {
+        #[snake_case]
+        table: String,
+        #[snake_case]
+        r#type: &'a str,
+        description: String,
+    }
+
+    let mut config = Config {
+        table: "users".to_owned(),
+        r#type: "_internal",
+        description: "Not An Identifier".to_owned(),
+    };
+    assert_eq!(config.validate_idents(), Ok(()));
+
+    config.r#type = "2fa";
+    assert_eq!(
+        config.validate_idents(),
+        Err(FieldError {
+            field: "type",
+            error: InvalidSnakeCase::InvalidStartChar {
+                char: '2',
+                offset: 0
+            },
+        })
+    );
+
+    config.table = "Users".to_owned();
+    assert_eq!(config.validate_idents().unwrap_err().field, "table");
+}
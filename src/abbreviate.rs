@@ -0,0 +1,129 @@
+use alloc::{borrow::ToOwned as _, string::String, vec::Vec};
+
+use crate::{SnakeCase, SnakeStr};
+
+/// Shortening, for when identifiers must fit a length limit (like the 63 bytes of a PostgreSQL identifier).
+impl SnakeStr {
+    /// Shorten to at most `max_len` bytes by truncating words, longest first, keeping the underscores between them:
+    ///
+    /// ```
+    /// # use snake_case::SnakeStr;
+    /// let name = SnakeStr::try_from_str("customer_relationship_manager").unwrap();
+    /// assert_eq!(name.abbreviate(30), "customer_relationship_manager");
+    /// assert_eq!(name.abbreviate(20), "custom_relati_manage");
+    /// assert_eq!(name.abbreviate(12), "cust_rel_man");
+    /// assert_eq!(name.abbreviate(4), "crm");
+    /// ```
+    ///
+    /// Words that are all digits (like the `2` in `table_2`) are not truncated.
+    /// If the words don't fit even at one letter each, this falls back to [`Self::abbreviate_initials`].
+    /// Leading underscores are kept, consecutive and trailing ones are dropped.
+    ///
+    /// The result is never empty, so it can be longer than `max_len` if that is zero.
+    pub fn abbreviate(&self, max_len: usize) -> SnakeCase {
+        if self.len() <= max_len {
+            return self.to_owned();
+        }
+
+        let prefix = self.leading_underscores();
+        let words: Vec<&str> = self.words().collect();
+        if words.is_empty() {
+            return self.abbreviate_initials(max_len); // only underscores
+        }
+        let budget = max_len
+            .saturating_sub(prefix.len())
+            .saturating_sub(words.len().saturating_sub(1));
+        let mut lens: Vec<usize> = words.iter().map(|word| word.len()).collect();
+        while lens.iter().sum::<usize>() > budget {
+            let longest = lens
+                .iter()
+                .enumerate()
+                .filter(|&(i, &len)| len > 1 && !is_number(words[i]))
+                .max_by_key(|&(_, &len)| len); // the last of the longest, so the first words stay recognizable
+            match longest {
+                Some((i, _)) => lens[i] -= 1,
+                None => return self.abbreviate_initials(max_len),
+            }
+        }
+
+        let mut string = String::from(prefix);
+        for (i, (word, len)) in words.iter().zip(lens).enumerate() {
+            if i > 0 {
+                string.push('_');
+            }
+            string.push_str(&word[..len]);
+        }
+        SnakeCase::from_string_unchecked(string)
+    }
+
+    /// Shorten to the first letter of each word, then truncate to `max_len` bytes if that is still too long:
+    ///
+    /// ```
+    /// # use snake_case::SnakeStr;
+    /// let name = SnakeStr::try_from_str("_customer_relationship_manager").unwrap();
+    /// assert_eq!(name.abbreviate_initials(10), "_crm");
+    /// assert_eq!(name.abbreviate_initials(3), "_cr");
+    /// ```
+    ///
+    /// Like [`Self::abbreviate`], strings that already fit are returned unchanged,
+    /// and the result is never empty.
+    pub fn abbreviate_initials(&self, max_len: usize) -> SnakeCase {
+        if self.len() <= max_len {
+            return self.to_owned();
+        }
+        let mut string = String::from(self.leading_underscores());
+        string.extend(self.words().map(|word| char::from(word.as_bytes()[0])));
+        // A non-empty prefix of snake_case is still snake_case:
+        string.truncate(max_len.max(1));
+        SnakeCase::from_string_unchecked(string)
+    }
+
+    fn leading_underscores(&self) -> &str {
+        let s = self.as_str();
+        &s[..s.len() - s.trim_start_matches('_').len()]
+    }
+}
+
+fn is_number(word: &str) -> bool {
+    word.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snake(s: &str) -> &SnakeStr {
+        SnakeStr::try_from_str(s).unwrap()
+    }
+
+    #[test]
+    fn abbreviate() {
+        assert_eq!(snake("user_id").abbreviate(7), "user_id");
+        assert_eq!(snake("user_id").abbreviate(6), "use_id");
+        assert_eq!(snake("orders_archive_2024").abbreviate(12), "ord_arc_2024");
+        assert_eq!(snake("__private__field_").abbreviate(12), "__priva_fiel");
+        assert_eq!(snake("a_b_c_d").abbreviate(5), "abcd"); // falls back to initials
+        assert_eq!(snake("a_b_c_d").abbreviate(0), "a");
+
+        for max_len in 1..30 {
+            let short = snake("_the_quick_brown_fox_2").abbreviate(max_len);
+            assert!(short.len() <= max_len, "{}", short);
+        }
+
+        for s in ["___", "_"] {
+            for max_len in 1..5 {
+                let short = snake(s).abbreviate(max_len);
+                assert!(short.len() <= max_len, "{}", short);
+            }
+        }
+        assert_eq!(snake("___").abbreviate(1), "_");
+    }
+
+    #[test]
+    fn abbreviate_initials() {
+        assert_eq!(snake("http_server").abbreviate_initials(11), "http_server");
+        assert_eq!(snake("http_server_2").abbreviate_initials(5), "hs2");
+        assert_eq!(snake("__").abbreviate_initials(1), "_");
+        assert_eq!(snake("long_name").abbreviate_initials(0), "l");
+    }
+}
@@ -0,0 +1,171 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    convert, CamelCase, ConvertOptions, InvalidCamelCase, InvalidPascalCase, InvalidSnakeCase,
+    PascalCase, SnakeCase, SnakeStr,
+};
+
+/// Words that are written in all caps in camelCase and PascalCase, like `HTTP` and `ID`.
+///
+/// Without one, `http_server_id` becomes `HttpServerId`. With one, you choose:
+///
+/// ```
+/// # use snake_case::{AcronymSet, SnakeCase, SnakeCaseRef};
+/// const ACRONYMS: AcronymSet<'static> = AcronymSet::new(&["api", "http", "id"]);
+///
+/// let id = SnakeCaseRef::try_from_str("http_server_id").unwrap();
+/// assert_eq!(id.to_pascal_case().unwrap(), "HttpServerId");
+/// assert_eq!(id.to_pascal_case_with(&ACRONYMS).unwrap(), "HTTPServerID");
+/// assert_eq!(id.to_camel_case_with(&ACRONYMS).unwrap(), "httpServerID");
+///
+/// // Acronyms also split runs of capitals that would otherwise be a single word:
+/// assert_eq!(SnakeCase::from_any_case("HTTPAPIClient").unwrap(), "httpapi_client");
+/// assert_eq!(SnakeCase::from_any_case_with("HTTPAPIClient", &ACRONYMS).unwrap(), "http_api_client");
+/// ```
+///
+/// The acronyms are matched ignoring case, and are looked up with a linear scan,
+/// so this is meant for a handful of them.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AcronymSet<'a> {
+    acronyms: &'a [&'a str],
+}
+
+impl<'a> AcronymSet<'a> {
+    pub const fn new(acronyms: &'a [&'a str]) -> Self {
+        Self { acronyms }
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.acronyms
+            .iter()
+            .any(|acronym| acronym.eq_ignore_ascii_case(word))
+    }
+
+    /// Split a run of capitals like `HTTPAPI` into known acronyms (`HTTP`, `API`).
+    ///
+    /// Returns `false` (leaving `out` as it was) if that isn't possible.
+    pub(crate) fn split<'w>(&self, word: &'w str, out: &mut Vec<&'w str>) -> bool {
+        let max_len = self.acronyms.iter().map(|a| a.len()).max().unwrap_or(0);
+
+        // `ends[i]` is where the first acronym of a split of `word[i..]` ends, if there is one.
+        // Working backwards and trying the longest match first, so `HTTPS` wins over `HTTP` + `S`.
+        let mut ends: Vec<Option<usize>> = Vec::with_capacity(word.len() + 1);
+        ends.resize(word.len() + 1, None);
+        ends[word.len()] = Some(word.len());
+        for start in (0..word.len()).rev() {
+            ends[start] = (start + 1..=word.len().min(start + max_len))
+                .rev()
+                .filter(|&end| word.is_char_boundary(start) && word.is_char_boundary(end))
+                .find(|&end| ends[end].is_some() && self.contains(&word[start..end]));
+        }
+
+        if ends[0].is_none() {
+            return false;
+        }
+        let mut start = 0;
+        while start < word.len() {
+            let end = ends[start].expect("a split was found");
+            out.push(&word[start..end]);
+            start = end;
+        }
+        true
+    }
+}
+
+impl SnakeCase {
+    /// Like [`Self::from_any_case`], but also splitting runs of capitals into the given acronyms,
+    /// so `HTTPAPIClient` becomes `http_api_client`.
+    ///
+    /// See [`ConvertOptions`] for more control.
+    pub fn from_any_case_with(
+        s: &str,
+        acronyms: &AcronymSet<'_>,
+    ) -> Result<SnakeCase, InvalidSnakeCase> {
+        ConvertOptions::new().acronyms(*acronyms).to_snake_case(s)
+    }
+}
+
+impl SnakeStr {
+    /// Like [`Self::to_pascal_case`], but with the acronyms in all caps: `http_server` -> `HTTPServer`.
+    pub fn to_pascal_case_with(
+        &self,
+        acronyms: &AcronymSet<'_>,
+    ) -> Result<PascalCase, InvalidPascalCase> {
+        PascalCase::try_from_string(join_with_acronyms(self, acronyms, true))
+    }
+
+    /// Like converting to [`CamelCase`], but with the acronyms in all caps: `server_id` -> `serverID`.
+    ///
+    /// An acronym as the first word is still all lower case: `http_server` -> `httpServer`.
+    pub fn to_camel_case_with(
+        &self,
+        acronyms: &AcronymSet<'_>,
+    ) -> Result<CamelCase, InvalidCamelCase> {
+        CamelCase::try_from_string(join_with_acronyms(self, acronyms, false))
+    }
+}
+
+fn join_with_acronyms(
+    snake: &SnakeStr,
+    acronyms: &AcronymSet<'_>,
+    capitalize_first: bool,
+) -> String {
+    let mut out = String::with_capacity(snake.len());
+    for word in snake.words() {
+        let first = out.is_empty();
+        if acronyms.contains(word) && (capitalize_first || !first) {
+            out.extend(word.chars().map(|c| c.to_ascii_uppercase()));
+        } else {
+            out.push_str(&convert::join_camel_case(
+                core::iter::once(word),
+                capitalize_first || !first,
+            ));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACRONYMS: AcronymSet<'static> = AcronymSet::new(&["http", "https", "id", "url", "xml"]);
+
+    #[test]
+    fn to_pascal_and_camel() {
+        let snake = |s| SnakeCase::try_from_str(s).unwrap();
+        let pascal = |s| snake(s).to_pascal_case_with(&ACRONYMS).unwrap();
+        assert_eq!(pascal("xml_http_request"), "XMLHTTPRequest");
+        assert_eq!(pascal("user_id"), "UserID");
+        assert_eq!(pascal("identity"), "Identity");
+        assert_eq!(pascal("_url_2"), "URL2");
+
+        let camel = |s| snake(s).to_camel_case_with(&ACRONYMS).unwrap();
+        assert_eq!(camel("url_parser"), "urlParser");
+        assert_eq!(camel("parse_url"), "parseURL");
+        assert!(snake("_2").to_camel_case_with(&ACRONYMS).is_err());
+    }
+
+    #[test]
+    fn from_any_case() {
+        let from = |s| SnakeCase::from_any_case_with(s, &ACRONYMS).unwrap();
+        assert_eq!(from("HTTPServerID"), "http_server_id");
+        assert_eq!(from("XMLHTTPSURL"), "xml_https_url");
+        assert_eq!(from("HTTPSID"), "https_id");
+        assert_eq!(from("ABCServer"), "abc_server"); // unknown, so left alone
+        assert_eq!(from("IDList"), "id_list");
+        assert!(SnakeCase::from_any_case_with("--", &ACRONYMS).is_err());
+    }
+
+    #[test]
+    fn long_run_of_capitals() {
+        // Every prefix matches but no split exists, which is exponential with naive backtracking:
+        let acronyms = AcronymSet::new(&["a", "aa"]);
+        let input = ["A".repeat(10_000), "B".into()].concat();
+        let snake = SnakeCase::from_any_case_with(&input, &acronyms).unwrap();
+        assert_eq!(snake, input.to_ascii_lowercase().as_str());
+
+        let split = SnakeCase::from_any_case_with(&"A".repeat(1_000), &acronyms).unwrap();
+        assert_eq!(split.len(), 1_000 + 499); // `aa_aa_..`
+    }
+}
@@ -0,0 +1,42 @@
+use alloc::string::String;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::SnakeCase;
+
+const START_CHARS: &[u8] = b"_abcdefghijklmnopqrstuvwxyz";
+const CHARS: &[u8] = b"_abcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Builds the string one valid character at a time, so every input produces valid snake_case
+/// (no rejection sampling).
+impl<'a> Arbitrary<'a> for SnakeCase {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.arbitrary_len::<u8>()?;
+        let mut string = String::with_capacity(len + 1);
+        string.push(*u.choose(START_CHARS)? as char);
+        for _ in 0..len {
+            string.push(*u.choose(CHARS)? as char);
+        }
+        Ok(SnakeCase::from_string_unchecked(string))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn always_valid() {
+        let data: alloc::vec::Vec<u8> = (0..=255).chain((0..=255).rev()).collect();
+        for start in 0..data.len() {
+            let mut u = Unstructured::new(&data[start..]);
+            let snake = SnakeCase::arbitrary(&mut u).unwrap();
+            assert!(crate::is_snake_case(snake.as_str()), "{:?}", snake);
+        }
+        assert!(SnakeCase::arbitrary(&mut Unstructured::new(&[])).is_ok());
+    }
+}
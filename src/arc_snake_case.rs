@@ -0,0 +1,111 @@
+use core::convert::TryFrom;
+
+use alloc::{string::String, sync::Arc};
+
+use crate::{InvalidSnakeCase, SnakeCase, SnakeCaseRef, SnakeStr};
+
+/// A reference-counted string type that can only contain valid snake_case.
+///
+/// Cloning it only bumps a reference count, so it is cheap to share between threads and data structures.
+/// Otherwise it works just like [`SnakeCase`], and derefs to [`SnakeStr`].
+///
+/// ```
+/// # use snake_case::ArcSnakeCase;
+/// let table: ArcSnakeCase = "user_accounts".parse().unwrap();
+/// let shared = table.clone();
+/// assert!(ArcSnakeCase::ptr_eq(&table, &shared));
+/// assert_eq!(shared.word_count(), 2);
+/// ```
+pub type ArcSnakeCase = SnakeCase<Arc<str>>;
+
+impl SnakeCase<Arc<str>> {
+    /// Do both point to the same allocation?
+    pub fn ptr_eq(this: &ArcSnakeCase, other: &ArcSnakeCase) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+
+    /// Returns the inner `Arc<str>`, without copying.
+    pub fn into_arc_str(self) -> Arc<str> {
+        self.0
+    }
+}
+
+impl From<SnakeCase> for ArcSnakeCase {
+    fn from(snake: SnakeCase) -> Self {
+        SnakeCase(snake.as_str().into())
+    }
+}
+
+impl From<SnakeCaseRef<'_>> for ArcSnakeCase {
+    fn from(snake: SnakeCaseRef<'_>) -> Self {
+        SnakeCase(snake.as_str().into())
+    }
+}
+
+impl From<&SnakeStr> for ArcSnakeCase {
+    fn from(snake: &SnakeStr) -> Self {
+        SnakeCase(snake.as_str().into())
+    }
+}
+
+impl From<ArcSnakeCase> for SnakeCase {
+    fn from(snake: ArcSnakeCase) -> Self {
+        SnakeCase(snake.as_str().into())
+    }
+}
+
+impl From<ArcSnakeCase> for Arc<str> {
+    fn from(snake: ArcSnakeCase) -> Self {
+        snake.into_arc_str()
+    }
+}
+
+impl TryFrom<&str> for ArcSnakeCase {
+    type Error = InvalidSnakeCase;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        SnakeCase::try_new(s.into())
+    }
+}
+
+impl TryFrom<String> for ArcSnakeCase {
+    type Error = InvalidSnakeCase;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        SnakeCase::try_new(s.into())
+    }
+}
+
+impl core::str::FromStr for ArcSnakeCase {
+    type Err = InvalidSnakeCase;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SnakeCase::try_new(s.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arc_snake_case() {
+        let snake = SnakeCase::try_from_str("query_plan").unwrap();
+        let arc = ArcSnakeCase::from(snake.clone());
+        assert_eq!(arc, snake);
+        assert_eq!(arc.clone(), "query_plan");
+        assert!(ArcSnakeCase::ptr_eq(&arc, &arc.clone()));
+        let back: SnakeCase = arc.into();
+        assert_eq!(back, snake);
+
+        assert!(ArcSnakeCase::try_from("QueryPlan").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let arc: ArcSnakeCase = serde_json::from_str(r#""foo_bar""#).unwrap();
+        assert_eq!(serde_json::to_string(&arc).unwrap(), r#""foo_bar""#);
+        assert!(serde_json::from_str::<ArcSnakeCase>(r#""fooBar""#).is_err());
+    }
+}
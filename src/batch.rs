@@ -0,0 +1,75 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{swar::validate_fast, InvalidSnakeCase};
+
+/// The strings that [`validate_all`] found to be invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchError {
+    /// The index of each invalid string, and what is wrong with it. Never empty.
+    pub invalid: Vec<(usize, InvalidSnakeCase)>,
+}
+
+impl BatchError {
+    /// The first invalid string.
+    pub fn first(&self) -> (usize, &InvalidSnakeCase) {
+        let (index, error) = &self.invalid[0];
+        (*index, error)
+    }
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} invalid snake_case string(s):", self.invalid.len())?;
+        for (index, error) in &self.invalid {
+            write!(f, "\n  [{}]: {}", index, error)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {}
+
+/// Validate many strings at once, reporting every invalid one.
+///
+/// ```
+/// # use snake_case::{validate_all, InvalidSnakeCase};
+/// assert!(validate_all(["id", "user_name"]).is_ok());
+///
+/// let err = validate_all(["id", "userName", "", "email"]).unwrap_err();
+/// assert_eq!(err.first(), (1, &InvalidSnakeCase::UppercaseChar { offset: 4 }));
+/// assert_eq!(err.invalid.len(), 2);
+/// ```
+pub fn validate_all<'a>(strings: impl IntoIterator<Item = &'a str>) -> Result<(), BatchError> {
+    let invalid: Vec<_> = strings
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, s)| validate_fast(s).err().map(|error| (index, error)))
+        .collect();
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(BatchError { invalid })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString as _;
+
+    use super::*;
+
+    #[test]
+    fn batch() {
+        assert_eq!(validate_all(Vec::new()), Ok(()));
+
+        let err = validate_all(["a", "2", "b", "c-d"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "2 invalid snake_case string(s):\n  \
+            [1]: snake_case must start with a lower case letter or underscore, found '2' at offset 0\n  \
+            [3]: invalid character '-' at offset 1: snake_case may only contain lower case letters, digits and underscores"
+        );
+    }
+}
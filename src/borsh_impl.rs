@@ -0,0 +1,40 @@
+use alloc::string::{String, ToString as _};
+
+use borsh::{
+    io::{Error, ErrorKind, Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+use crate::SnakeCase;
+
+/// Serialized the same way as a `String`.
+impl BorshSerialize for SnakeCase {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_str().serialize(writer)
+    }
+}
+
+/// Fails with [`ErrorKind::InvalidData`] if the string is not valid snake_case.
+impl BorshDeserialize for SnakeCase {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let string = String::deserialize_reader(reader)?;
+        SnakeCase::try_from_string(string)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let snake = SnakeCase::try_from_str("hello_world").unwrap();
+        let bytes = borsh::to_vec(&snake).unwrap();
+        assert_eq!(bytes, borsh::to_vec("hello_world").unwrap());
+        assert_eq!(borsh::from_slice::<SnakeCase>(&bytes).unwrap(), snake);
+
+        let bytes = borsh::to_vec("Hello").unwrap();
+        assert!(borsh::from_slice::<SnakeCase>(&bytes).is_err());
+    }
+}
@@ -0,0 +1,247 @@
+use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
+
+use crate::{InvalidSnakeCase, SnakeCase, SnakeStr};
+
+/// A [`SnakeCase`] of at most `MAX` bytes.
+///
+/// For identifiers with a length limit, like PostgreSQL's 63 bytes (longer ones are silently truncated):
+///
+/// ```
+/// # use snake_case::{BoundedSnakeCase, BoundedSnakeCaseError, SnakeStr};
+/// type PgIdent = BoundedSnakeCase<63>;
+///
+/// assert!(PgIdent::try_from_str("user_id").is_ok());
+/// assert_eq!(
+///     BoundedSnakeCase::<4>::try_from_str("user_id"),
+///     Err(BoundedSnakeCaseError::TooLong { len: 7, max: 4 })
+/// );
+///
+/// let long = SnakeStr::try_from_str("customer_relationship_manager").unwrap();
+/// assert_eq!(BoundedSnakeCase::<12>::abbreviated(long), "cust_rel_man");
+/// ```
+///
+/// Like [`SnakeCase`] it is checked on construction (and deserialization), so it can be trusted afterwards.
+#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BoundedSnakeCase<const MAX: usize>(SnakeCase);
+
+/// Why a [`BoundedSnakeCase`] could not be created.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BoundedSnakeCaseError {
+    /// The string was not valid snake_case.
+    Invalid(InvalidSnakeCase),
+
+    /// The string was valid, but `len` bytes long.
+    TooLong { len: usize, max: usize },
+}
+
+impl fmt::Display for BoundedSnakeCaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(err) => err.fmt(f),
+            Self::TooLong { len, max } => write!(
+                f,
+                "snake_case of {} bytes is longer than the maximum of {} bytes",
+                len, max
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BoundedSnakeCaseError {}
+
+impl From<InvalidSnakeCase> for BoundedSnakeCaseError {
+    fn from(err: InvalidSnakeCase) -> Self {
+        Self::Invalid(err)
+    }
+}
+
+impl<const MAX: usize> BoundedSnakeCase<MAX> {
+    /// The maximum length, in bytes.
+    pub const MAX: usize = MAX;
+
+    pub fn try_from_str(s: &str) -> Result<Self, BoundedSnakeCaseError> {
+        crate::validate(s)?;
+        Self::check_len(s.len())?;
+        Ok(Self(SnakeCase::from_string_unchecked(s.into())))
+    }
+
+    /// Returns the snake_case back, together with the error, if it is too long.
+    pub fn try_from_snake_case(
+        snake_case: SnakeCase,
+    ) -> Result<Self, (SnakeCase, BoundedSnakeCaseError)> {
+        match Self::check_len(snake_case.len()) {
+            Ok(()) => Ok(Self(snake_case)),
+            Err(err) => Err((snake_case, err)),
+        }
+    }
+
+    /// Shortened with [`SnakeStr::abbreviate`] if it is too long.
+    ///
+    /// Panics if `MAX` is zero, since snake_case can't be empty.
+    pub fn abbreviated(snake: &SnakeStr) -> Self {
+        assert!(MAX > 0, "BoundedSnakeCase<0> can't hold any snake_case");
+        Self::try_from_snake_case(snake.abbreviate(MAX))
+            .expect("abbreviate returns at most MAX bytes for MAX > 0")
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn as_snake_case(&self) -> &SnakeCase {
+        &self.0
+    }
+
+    pub fn into_snake_case(self) -> SnakeCase {
+        self.0
+    }
+
+    fn check_len(len: usize) -> Result<(), BoundedSnakeCaseError> {
+        if len <= MAX {
+            Ok(())
+        } else {
+            Err(BoundedSnakeCaseError::TooLong { len, max: MAX })
+        }
+    }
+}
+
+impl<const MAX: usize> Deref for BoundedSnakeCase<MAX> {
+    type Target = SnakeStr;
+
+    fn deref(&self) -> &SnakeStr {
+        &self.0
+    }
+}
+
+impl<const MAX: usize> AsRef<str> for BoundedSnakeCase<MAX> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const MAX: usize> AsRef<SnakeStr> for BoundedSnakeCase<MAX> {
+    fn as_ref(&self) -> &SnakeStr {
+        &self.0
+    }
+}
+
+impl<const MAX: usize> PartialEq<str> for BoundedSnakeCase<MAX> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const MAX: usize> PartialEq<&str> for BoundedSnakeCase<MAX> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const MAX: usize> From<BoundedSnakeCase<MAX>> for SnakeCase {
+    fn from(bounded: BoundedSnakeCase<MAX>) -> Self {
+        bounded.0
+    }
+}
+
+impl<const MAX: usize> TryFrom<&str> for BoundedSnakeCase<MAX> {
+    type Error = BoundedSnakeCaseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const MAX: usize> TryFrom<SnakeCase> for BoundedSnakeCase<MAX> {
+    type Error = BoundedSnakeCaseError;
+
+    fn try_from(snake_case: SnakeCase) -> Result<Self, Self::Error> {
+        Self::try_from_snake_case(snake_case).map_err(|(_, err)| err)
+    }
+}
+
+impl<const MAX: usize> FromStr for BoundedSnakeCase<MAX> {
+    type Err = BoundedSnakeCaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const MAX: usize> fmt::Debug for BoundedSnakeCase<MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl<const MAX: usize> fmt::Display for BoundedSnakeCase<MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const MAX: usize> serde::Serialize for BoundedSnakeCase<MAX> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const MAX: usize> serde::Deserialize<'de> for BoundedSnakeCase<MAX> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let snake_case = SnakeCase::deserialize(deserializer)?;
+        Self::try_from_snake_case(snake_case).map_err(|(_, err)| serde::de::Error::custom(err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString as _;
+
+    use super::*;
+
+    #[test]
+    fn bounded() {
+        type Short = BoundedSnakeCase<8>;
+        assert_eq!(Short::try_from_str("user_id").unwrap(), "user_id");
+        assert_eq!(
+            Short::try_from_str("12345678"),
+            Err(InvalidSnakeCase::InvalidStartChar {
+                char: '1',
+                offset: 0
+            }
+            .into())
+        );
+        let err = "very_long_name".parse::<Short>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "snake_case of 14 bytes is longer than the maximum of 8 bytes"
+        );
+
+        let (snake_case, _) =
+            Short::try_from_snake_case(SnakeCase::try_from_str("very_long_name").unwrap())
+                .unwrap_err();
+        assert_eq!(snake_case, "very_long_name");
+        assert_eq!(
+            Short::abbreviated(SnakeStr::try_from_str("very_long_name").unwrap()),
+            "ve_lo_na"
+        );
+        assert_eq!(Short::MAX, 8);
+
+        let underscores = SnakeStr::try_from_str("___").unwrap();
+        assert!(BoundedSnakeCase::<1>::abbreviated(underscores).len() <= 1);
+        assert!(BoundedSnakeCase::<2>::abbreviated(underscores).len() <= 2);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let name: BoundedSnakeCase<8> = serde_json::from_str(r#""user_id""#).unwrap();
+        assert_eq!(serde_json::to_string(&name).unwrap(), r#""user_id""#);
+        let err = serde_json::from_str::<BoundedSnakeCase<4>>(r#""user_id""#).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("longer than the maximum of 4 bytes"));
+    }
+}
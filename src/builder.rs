@@ -0,0 +1,119 @@
+use alloc::string::String;
+
+use crate::{validate_word, InvalidSnakeCase, SnakeCase};
+
+/// Builds a [`SnakeCase`] one word at a time, joining the words with underscores.
+///
+/// Each word is validated as it is pushed, so the result never needs to be re-validated.
+///
+/// ```
+/// # use snake_case::SnakeCaseBuilder;
+/// # fn main() -> Result<(), snake_case::InvalidSnakeCase> {
+/// let mut builder = SnakeCaseBuilder::new();
+/// builder.push_word("http")?.push_word("server")?.push_number(2);
+/// assert_eq!(builder.build()?, "http_server_2");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SnakeCaseBuilder {
+    string: String,
+}
+
+impl SnakeCaseBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a word, which must be non-empty and consist of only lower case ASCII letters and digits.
+    ///
+    /// If the very first word starts with a digit, the result gets a leading underscore (`_2d`).
+    ///
+    /// Error offsets refer to where the offending character would have ended up in the built string.
+    pub fn push_word(&mut self, word: &str) -> Result<&mut Self, InvalidSnakeCase> {
+        validate_word(word, self.string.len() + self.separator(word).len())?;
+
+        self.string.push_str(self.separator(word));
+        self.string.push_str(word);
+        Ok(self)
+    }
+
+    /// Append a number as a word.
+    ///
+    /// If this is the first word, the result gets a leading underscore (`_2`).
+    pub fn push_number(&mut self, number: u64) -> &mut Self {
+        use core::fmt::Write as _;
+        self.string.push('_');
+        write!(self.string, "{}", number).ok();
+        self
+    }
+
+    /// Have any words been pushed?
+    pub fn is_empty(&self) -> bool {
+        self.string.is_empty()
+    }
+
+    /// Fails with [`InvalidSnakeCase::Empty`] if no words have been pushed.
+    pub fn build(self) -> Result<SnakeCase, InvalidSnakeCase> {
+        if self.string.is_empty() {
+            Err(InvalidSnakeCase::Empty)
+        } else {
+            Ok(SnakeCase::from_string_unchecked(self.string))
+        }
+    }
+
+    /// What goes before `word`: an underscore between words,
+    /// or before a first word that starts with a digit.
+    fn separator(&self, word: &str) -> &'static str {
+        let starts_with_digit = matches!(word.as_bytes().first(), Some(b) if b.is_ascii_digit());
+        if !self.string.is_empty() || starts_with_digit {
+            "_"
+        } else {
+            ""
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder() {
+        let mut builder = SnakeCaseBuilder::new();
+        assert!(builder.is_empty());
+        builder
+            .push_word("max")
+            .unwrap()
+            .push_word("depth2")
+            .unwrap();
+        assert_eq!(builder.clone().build().unwrap(), "max_depth2");
+
+        assert_eq!(
+            builder.push_word("Foo").unwrap_err(),
+            InvalidSnakeCase::UppercaseChar { offset: 11 }
+        );
+        assert_eq!(
+            builder.push_word("a_b").unwrap_err(),
+            InvalidSnakeCase::InvalidChar {
+                char: '_',
+                offset: 12
+            }
+        );
+        assert_eq!(builder.push_word("").unwrap_err(), InvalidSnakeCase::Empty);
+        assert_eq!(builder.build().unwrap(), "max_depth2");
+
+        let mut builder = SnakeCaseBuilder::new();
+        builder.push_number(3).push_word("d").unwrap();
+        assert_eq!(builder.build().unwrap(), "_3_d");
+
+        let mut builder = SnakeCaseBuilder::new();
+        builder.push_word("2d").unwrap();
+        assert_eq!(builder.build().unwrap(), "_2d");
+
+        assert_eq!(
+            SnakeCaseBuilder::new().build().unwrap_err(),
+            InvalidSnakeCase::Empty
+        );
+    }
+}
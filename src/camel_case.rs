@@ -0,0 +1,145 @@
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+
+#[cfg(feature = "alloc")]
+use crate::{convert, SnakeCase, SnakeCaseRef};
+
+/// Is the given string a non-empty lowerCamelCase string?
+/// In particular, does it match  ^[a-z][a-zA-Z0-9]*$  ?
+pub const fn is_camel_case(string: &str) -> bool {
+    let bytes = string.as_bytes();
+    if bytes.is_empty() || !bytes[0].is_ascii_lowercase() {
+        return false;
+    }
+    let mut i = 1;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_alphanumeric() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Only one possible error: the given string was not valid camelCase.
+#[derive(Clone, Debug)]
+pub struct InvalidCamelCase;
+
+case_types! {
+    /// An owning string type that can only contain valid lowerCamelCase.
+    /// In other words, it always matches  ^[a-z][a-zA-Z0-9]*$
+    /// * Non-empty
+    /// * Starts with a lower case ASCII letter
+    /// * Contains only ASCII letters and digits
+    pub struct CamelCase;
+
+    /// An non-owning string type that can only refer to string containing valid lowerCamelCase.
+    /// In other words, it always matches  ^[a-z][a-zA-Z0-9]*$
+    /// * Non-empty
+    /// * Starts with a lower case ASCII letter
+    /// * Contains only ASCII letters and digits
+    pub struct CamelCaseRef;
+
+    error: InvalidCamelCase,
+    validator: is_camel_case,
+    expected: "camelCase",
+}
+
+// ----------------------------------------------------------------------------
+// camelCase -> snake_case never fails, since camelCase always starts with a letter.
+
+#[cfg(feature = "alloc")]
+impl From<CamelCaseRef<'_>> for SnakeCase {
+    fn from(camel: CamelCaseRef<'_>) -> Self {
+        SnakeCase::from_any_case(camel.as_str()).expect("camelCase starts with a letter")
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&CamelCase> for SnakeCase {
+    fn from(camel: &CamelCase) -> Self {
+        camel.as_ref().into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<CamelCase> for SnakeCase {
+    fn from(camel: CamelCase) -> Self {
+        camel.as_ref().into()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// snake_case -> camelCase fails for strings like `_` or `_42`.
+
+#[cfg(feature = "alloc")]
+impl TryFrom<SnakeCaseRef<'_>> for CamelCase {
+    type Error = InvalidCamelCase;
+
+    fn try_from(snake: SnakeCaseRef<'_>) -> Result<Self, Self::Error> {
+        CamelCase::try_from_string(convert::join_camel_case(snake.words(), false))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&SnakeCase> for CamelCase {
+    type Error = InvalidCamelCase;
+
+    fn try_from(snake: &SnakeCase) -> Result<Self, Self::Error> {
+        CamelCase::try_from(snake.as_ref())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<SnakeCase> for CamelCase {
+    type Error = InvalidCamelCase;
+
+    fn try_from(snake: SnakeCase) -> Result<Self, Self::Error> {
+        CamelCase::try_from(snake.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString as _;
+
+    use super::*;
+
+    #[test]
+    fn camel_case() {
+        assert!(is_camel_case("helloWorld42"));
+        assert!(is_camel_case("h"));
+        assert!(!is_camel_case(""));
+        assert!(!is_camel_case("HelloWorld"));
+        assert!(!is_camel_case("hello_world"));
+        assert!(!is_camel_case("helloWorld!"));
+
+        assert_eq!(CamelCase::try_from_str("helloWorld").unwrap(), "helloWorld");
+        assert_eq!(
+            "helloWorld",
+            CamelCaseRef::try_from_str("helloWorld").unwrap()
+        );
+        assert!(CamelCaseRef::try_from_str("42").is_err());
+        assert_eq!("helloWorld".parse::<CamelCase>().unwrap(), "helloWorld");
+        assert_eq!(
+            "hello_world".parse::<CamelCase>().unwrap_err().to_string(),
+            "expected camelCase"
+        );
+    }
+
+    #[test]
+    fn camel_snake_conversions() {
+        let snake = SnakeCase::try_from_str("__http_server_2_go_").unwrap();
+        let camel = CamelCase::try_from(&snake).unwrap();
+        assert_eq!(camel, "httpServer2Go");
+        assert_eq!(SnakeCase::from(camel), "http_server2_go");
+
+        let camel = CamelCaseRef::try_from_str("apiFieldName").unwrap();
+        let snake = SnakeCase::from(camel);
+        assert_eq!(snake, "api_field_name");
+        assert_eq!(CamelCase::try_from(snake).unwrap(), camel.as_str());
+
+        assert!(CamelCase::try_from(SnakeCaseRef::try_from_str("_").unwrap()).is_err());
+        assert!(CamelCase::try_from(SnakeCaseRef::try_from_str("_42").unwrap()).is_err());
+    }
+}
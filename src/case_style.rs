@@ -0,0 +1,102 @@
+use crate::{is_camel_case, is_kebab_case, is_pascal_case, is_screaming_snake_case, is_snake_case};
+
+/// The case style of an identifier, as detected by [`detect_case`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CaseStyle {
+    /// `snake_case`
+    Snake,
+
+    /// `camelCase`
+    Camel,
+
+    /// `PascalCase`
+    Pascal,
+
+    /// `kebab-case`
+    Kebab,
+
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+
+    /// Only ASCII letters, digits, underscores and hyphens, but not any one style,
+    /// e.g. `hello_World` or `foo-bar_baz`.
+    Mixed,
+
+    /// Empty, or containing anything other than ASCII letters, digits, underscores and hyphens.
+    Unknown,
+}
+
+/// Detect the case style of the given identifier.
+///
+/// Some strings match several styles. A single lower case word like `hello` is valid snake_case,
+/// camelCase and kebab-case, and is reported as [`CaseStyle::Snake`].
+/// A single upper case word like `HELLO` is reported as [`CaseStyle::ScreamingSnake`].
+///
+/// ```
+/// # use snake_case::{detect_case, CaseStyle};
+/// assert_eq!(detect_case("hello_world"), CaseStyle::Snake);
+/// assert_eq!(detect_case("helloWorld"), CaseStyle::Camel);
+/// assert_eq!(detect_case("HelloWorld"), CaseStyle::Pascal);
+/// assert_eq!(detect_case("hello-world"), CaseStyle::Kebab);
+/// assert_eq!(detect_case("HELLO_WORLD"), CaseStyle::ScreamingSnake);
+/// assert_eq!(detect_case("Hello_world"), CaseStyle::Mixed);
+/// assert_eq!(detect_case("hello world"), CaseStyle::Unknown);
+/// ```
+pub const fn detect_case(string: &str) -> CaseStyle {
+    if is_snake_case(string) {
+        CaseStyle::Snake
+    } else if is_screaming_snake_case(string) {
+        CaseStyle::ScreamingSnake
+    } else if is_camel_case(string) {
+        CaseStyle::Camel
+    } else if is_pascal_case(string) {
+        CaseStyle::Pascal
+    } else if is_kebab_case(string) {
+        CaseStyle::Kebab
+    } else if is_mixed(string) {
+        CaseStyle::Mixed
+    } else {
+        CaseStyle::Unknown
+    }
+}
+
+const fn is_mixed(string: &str) -> bool {
+    let bytes = string.as_bytes();
+    let mut has_alphanumeric = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b.is_ascii_alphanumeric() {
+            has_alphanumeric = true;
+        } else if b != b'_' && b != b'-' {
+            return false;
+        }
+        i += 1;
+    }
+    has_alphanumeric
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_case() {
+        use super::detect_case as detect;
+        assert_eq!(detect("hello"), CaseStyle::Snake);
+        assert_eq!(detect("_private"), CaseStyle::Snake);
+        assert_eq!(detect("_"), CaseStyle::Snake);
+        assert_eq!(detect("HELLO"), CaseStyle::ScreamingSnake);
+        assert_eq!(detect("Hello"), CaseStyle::Pascal);
+        assert_eq!(detect("httpServer2"), CaseStyle::Camel);
+        assert_eq!(detect("HTTPServer"), CaseStyle::Pascal);
+        assert_eq!(detect("dry-run"), CaseStyle::Kebab);
+        assert_eq!(detect("foo-bar_baz"), CaseStyle::Mixed);
+        assert_eq!(detect("-Foo"), CaseStyle::Mixed);
+        assert_eq!(detect("42"), CaseStyle::Mixed);
+        assert_eq!(detect(""), CaseStyle::Unknown);
+        assert_eq!(detect("__--"), CaseStyle::Unknown);
+        assert_eq!(detect("stra\u{df}e"), CaseStyle::Unknown);
+    }
+}
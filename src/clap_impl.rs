@@ -0,0 +1,121 @@
+use std::ffi::OsStr;
+
+use clap::{
+    builder::TypedValueParser, builder::ValueParserFactory, error::ErrorKind, Arg, Command,
+};
+
+use crate::SnakeCase;
+
+/// Parses command line arguments as [`SnakeCase`], for `#[arg(value_parser = ...)]`.
+///
+/// `SnakeCase` implements [`ValueParserFactory`] with the strict parser,
+/// so a plain `name: SnakeCase` field in a `#[derive(Parser)]` struct is validated too.
+/// Invalid arguments get an error with a suggestion:
+///
+/// ```text
+/// error: invalid value 'userId' for '--name <NAME>': upper case character at offset 4: snake_case must be lower case
+///
+///   tip: try 'user_id'
+/// ```
+///
+/// ```
+/// use clap::{Arg, Command};
+/// use snake_case::{SnakeCase, SnakeCaseValueParser};
+///
+/// let command = Command::new("app")
+///     .arg(Arg::new("table").long("table").value_parser(SnakeCaseValueParser::lossy()));
+/// let matches = command.get_matches_from(["app", "--table", "UserProfiles"]);
+/// assert_eq!(matches.get_one::<SnakeCase>("table").unwrap(), "user_profiles");
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SnakeCaseValueParser {
+    lossy: bool,
+}
+
+impl SnakeCaseValueParser {
+    /// Rejects anything that isn't snake_case.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Converts other case styles with [`SnakeCase::from_any_case`] instead of rejecting them.
+    pub fn lossy() -> Self {
+        Self { lossy: true }
+    }
+}
+
+impl TypedValueParser for SnakeCaseValueParser {
+    type Value = SnakeCase;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<SnakeCase, clap::Error> {
+        let value = value.to_str().ok_or_else(|| {
+            clap::Error::raw(ErrorKind::InvalidUtf8, "invalid UTF-8 was detected\n").with_cmd(cmd)
+        })?;
+        let result = if self.lossy {
+            SnakeCase::from_any_case(value)
+        } else {
+            SnakeCase::try_from_str(value)
+        };
+        result.map_err(|err| {
+            let arg = arg.map_or_else(|| "...".to_owned(), |arg| arg.to_string());
+            let mut message = format!("invalid value '{}' for '{}': {}\n", value, arg, err);
+            if let (false, Ok(suggestion)) = (self.lossy, SnakeCase::from_any_case(value)) {
+                message += &format!("\n  tip: try '{}'\n", suggestion);
+            }
+            clap::Error::raw(ErrorKind::ValueValidation, message).with_cmd(cmd)
+        })
+    }
+}
+
+impl ValueParserFactory for SnakeCase {
+    type Parser = SnakeCaseValueParser;
+
+    fn value_parser() -> SnakeCaseValueParser {
+        SnakeCaseValueParser::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(parser: SnakeCaseValueParser, value: &str) -> Result<SnakeCase, clap::Error> {
+        Command::new("app")
+            .arg(Arg::new("name").long("name").value_parser(parser))
+            .try_get_matches_from(["app", "--name", value])
+            .map(|matches| matches.get_one::<SnakeCase>("name").unwrap().clone())
+    }
+
+    #[test]
+    fn value_parser() {
+        assert_eq!(
+            parse(SnakeCaseValueParser::new(), "user_id").unwrap(),
+            "user_id"
+        );
+        let err = parse(SnakeCaseValueParser::new(), "userId").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+        let message = err.to_string();
+        assert!(
+            message.contains("invalid value 'userId' for '--name <name>'"),
+            "{}",
+            message
+        );
+        assert!(message.contains("tip: try 'user_id'"), "{}", message);
+
+        assert_eq!(
+            parse(SnakeCaseValueParser::lossy(), "userId").unwrap(),
+            "user_id"
+        );
+        assert!(parse(SnakeCaseValueParser::lossy(), "!!").is_err());
+
+        let matches = Command::new("app")
+            .arg(Arg::new("name").value_parser(clap::value_parser!(SnakeCase)))
+            .try_get_matches_from(["app", "Name"]);
+        assert!(matches.is_err());
+    }
+}
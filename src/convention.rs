@@ -0,0 +1,405 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use {alloc::string::String, core::ops::Deref};
+
+/// A set of ASCII characters, for describing a [`Convention`].
+///
+/// ```
+/// # use snake_case::CharSet;
+/// const METRIC: CharSet = CharSet::LETTERS.union(CharSet::DIGITS).with(b'_').with(b':');
+/// assert!(METRIC.contains(b':'));
+/// assert!(!METRIC.contains(b'-'));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CharSet(u128);
+
+impl CharSet {
+    pub const EMPTY: CharSet = CharSet(0);
+    pub const LOWERCASE: CharSet = CharSet::range(b'a', b'z');
+    pub const UPPERCASE: CharSet = CharSet::range(b'A', b'Z');
+    pub const LETTERS: CharSet = CharSet::LOWERCASE.union(CharSet::UPPERCASE);
+    pub const DIGITS: CharSet = CharSet::range(b'0', b'9');
+
+    /// All ASCII characters from `first` to `last`, inclusive.
+    pub const fn range(first: u8, last: u8) -> CharSet {
+        let mut set = CharSet::EMPTY;
+        let mut c = first;
+        while c <= last && c < 128 {
+            set = set.with(c);
+            c += 1;
+        }
+        set
+    }
+
+    /// Non-ASCII bytes are ignored.
+    pub const fn with(self, c: u8) -> CharSet {
+        if c < 128 {
+            CharSet(self.0 | 1 << c)
+        } else {
+            self
+        }
+    }
+
+    pub const fn union(self, other: CharSet) -> CharSet {
+        CharSet(self.0 | other.0)
+    }
+
+    pub const fn contains(self, c: u8) -> bool {
+        c < 128 && self.0 & (1 << c) != 0
+    }
+}
+
+/// Which letters a [`Convention`] allows, on top of its [`CharSet`]s.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LetterCase {
+    Lower,
+    Upper,
+    Any,
+}
+
+/// Describes a naming convention: which characters may start a name, which may follow,
+/// which one separates the words, and which letter case is allowed.
+///
+/// snake_case is just one of them, and [`Self::SNAKE_CASE`] accepts exactly what [`crate::is_snake_case`] does.
+///
+/// ```
+/// # use snake_case::{CharSet, Convention, ConventionError, LetterCase};
+/// let metric = Convention::PROMETHEUS_METRIC.validator();
+/// let name = metric.check("http_requests:rate5m").unwrap();
+/// assert_eq!(name.words().collect::<Vec<_>>(), ["http", "requests:rate5m"]);
+/// assert_eq!(
+///     metric.validate("http-requests"),
+///     Err(ConventionError::InvalidChar { char: '-', offset: 4 })
+/// );
+///
+/// // Your own:
+/// const ENV_VAR: Convention = Convention::new("environment variable", CharSet::UPPERCASE, CharSet::DIGITS)
+///     .separator(b'_')
+///     .case(LetterCase::Upper);
+/// assert!(ENV_VAR.is_valid("RUST_LOG"));
+/// assert!(!ENV_VAR.is_valid("rust_log"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Convention {
+    name: &'static str,
+    start: CharSet,
+    body: CharSet,
+    separator: Option<u8>,
+    case: LetterCase,
+}
+
+impl Convention {
+    /// `^[_a-z][_a-z0-9]*$`
+    pub const SNAKE_CASE: Convention =
+        Convention::new("snake_case", CharSet::LOWERCASE, CharSet::DIGITS)
+            .separator(b'_')
+            .start_with_separator(true)
+            .case(LetterCase::Lower);
+
+    /// `^[_A-Z][_A-Z0-9]*$`
+    pub const SCREAMING_SNAKE_CASE: Convention =
+        Convention::new("SCREAMING_SNAKE_CASE", CharSet::UPPERCASE, CharSet::DIGITS)
+            .separator(b'_')
+            .start_with_separator(true)
+            .case(LetterCase::Upper);
+
+    /// A Prometheus metric name: `^[a-zA-Z_:][a-zA-Z0-9_:]*$`
+    pub const PROMETHEUS_METRIC: Convention = Convention::new(
+        "Prometheus metric name",
+        CharSet::LETTERS.with(b':'),
+        CharSet::LETTERS.union(CharSet::DIGITS).with(b':'),
+    )
+    .separator(b'_')
+    .start_with_separator(true);
+
+    /// A GraphQL name (of a field, type, argument, …): `^[_A-Za-z][_0-9A-Za-z]*$`
+    pub const GRAPHQL_NAME: Convention = Convention::new(
+        "GraphQL name",
+        CharSet::LETTERS,
+        CharSet::LETTERS.union(CharSet::DIGITS),
+    )
+    .separator(b'_')
+    .start_with_separator(true);
+
+    /// An SQL identifier that is portable without quoting: `^[a-z][a-z0-9_]*$`.
+    ///
+    /// Unquoted identifiers are case-insensitive, so only lower case is allowed,
+    /// and a leading underscore is not portable.
+    pub const SQL_IDENTIFIER: Convention =
+        Convention::new("SQL identifier", CharSet::LOWERCASE, CharSet::DIGITS)
+            .separator(b'_')
+            .case(LetterCase::Lower);
+
+    /// The separator (if any) is added to the body characters,
+    /// and the start characters are always allowed in the body too.
+    pub const fn new(name: &'static str, start: CharSet, body: CharSet) -> Convention {
+        Convention {
+            name,
+            start,
+            body: body.union(start),
+            separator: None,
+            case: LetterCase::Any,
+        }
+    }
+
+    /// The character between words, e.g. `_` or `-`. Allowed anywhere but at the start.
+    pub const fn separator(mut self, separator: u8) -> Convention {
+        self.separator = Some(separator);
+        self.body = self.body.with(separator);
+        self
+    }
+
+    /// Allow the separator at the start too (like `_private` in snake_case).
+    ///
+    /// Call this after [`Self::separator`].
+    pub const fn start_with_separator(mut self, allow: bool) -> Convention {
+        if let Some(separator) = self.separator {
+            if allow {
+                self.start = self.start.with(separator);
+            }
+        }
+        self
+    }
+
+    /// Restrict the letters to one case, even if the [`CharSet`]s contain both.
+    pub const fn case(mut self, case: LetterCase) -> Convention {
+        self.case = case;
+        self
+    }
+
+    /// What this convention is called, for error messages.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    const fn accepts(&self, set: CharSet, c: u8) -> bool {
+        let wrong_case = match self.case {
+            LetterCase::Lower => c.is_ascii_uppercase(),
+            LetterCase::Upper => c.is_ascii_lowercase(),
+            LetterCase::Any => false,
+        };
+        set.contains(c) && !wrong_case
+    }
+
+    /// Returns the first problem with the string, if any.
+    pub const fn validate(&self, s: &str) -> Result<(), ConventionError> {
+        let bytes = s.as_bytes();
+        if bytes.is_empty() {
+            return Err(ConventionError::Empty);
+        }
+        let mut offset = 0;
+        while offset < bytes.len() {
+            let set = if offset == 0 { self.start } else { self.body };
+            if !self.accepts(set, bytes[offset]) {
+                // `offset` is the start of a character, since everything before it was ASCII:
+                let char = crate::char_at(bytes, offset);
+                return Err(if offset == 0 {
+                    ConventionError::InvalidStartChar { char, offset }
+                } else {
+                    ConventionError::InvalidChar { char, offset }
+                });
+            }
+            offset += 1;
+        }
+        Ok(())
+    }
+
+    pub const fn is_valid(&self, s: &str) -> bool {
+        self.validate(s).is_ok()
+    }
+
+    /// For checking strings into [`Checked`] values that remember their convention.
+    pub const fn validator(&self) -> Validator<'_> {
+        Validator { convention: self }
+    }
+}
+
+/// Why a string doesn't follow a [`Convention`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConventionError {
+    Empty,
+    InvalidStartChar { char: char, offset: usize },
+    InvalidChar { char: char, offset: usize },
+}
+
+impl fmt::Display for ConventionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "found an empty string"),
+            Self::InvalidStartChar { char, offset } => {
+                write!(f, "may not start with {:?} (at offset {})", char, offset)
+            }
+            Self::InvalidChar { char, offset } => {
+                write!(f, "invalid character {:?} at offset {}", char, offset)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConventionError {}
+
+/// Checks strings against a [`Convention`]. See [`Convention::validator`].
+#[derive(Clone, Copy, Debug)]
+pub struct Validator<'conv> {
+    convention: &'conv Convention,
+}
+
+impl<'conv> Validator<'conv> {
+    pub fn convention(&self) -> &'conv Convention {
+        self.convention
+    }
+
+    pub fn validate(&self, s: &str) -> Result<(), ConventionError> {
+        self.convention.validate(s)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn check(&self, s: impl Into<String>) -> Result<Checked<'conv>, ConventionError> {
+        let value = s.into();
+        self.convention.validate(&value)?;
+        Ok(Checked {
+            convention: self.convention,
+            value,
+        })
+    }
+}
+
+/// A string that follows a [`Convention`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct Checked<'conv> {
+    convention: &'conv Convention,
+    value: String,
+}
+
+#[cfg(feature = "alloc")]
+impl<'conv> Checked<'conv> {
+    pub fn convention(&self) -> &'conv Convention {
+        self.convention
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    pub fn into_string(self) -> String {
+        self.value
+    }
+
+    /// Split by the separator of the convention (if any), skipping empty words.
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        let separator = self.convention.separator.map(char::from);
+        self.value
+            .split(move |c| Some(c) == separator)
+            .filter(|word| !word.is_empty())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Deref for Checked<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.value
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<str> for Checked<'_> {
+    fn as_ref(&self) -> &str {
+        &self.value
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for Checked<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for Checked<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<str> for Checked<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.value == other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<&str> for Checked<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.value == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn same_as_is_snake_case() {
+        for s in [
+            "", "a", "_", "a_b", "_a", "a_", "A", "a1", "1a", "a-b", "aB", "ö", "aö", "__", "a__b",
+        ] {
+            assert_eq!(
+                Convention::SNAKE_CASE.is_valid(s),
+                crate::is_snake_case(s),
+                "{:?}",
+                s
+            );
+            assert_eq!(
+                Convention::SCREAMING_SNAKE_CASE.is_valid(&s.to_uppercase()),
+                crate::is_screaming_snake_case(&s.to_uppercase()),
+                "{:?}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn presets() {
+        assert!(Convention::PROMETHEUS_METRIC.is_valid(":job:http_requests:rate5m"));
+        assert!(!Convention::PROMETHEUS_METRIC.is_valid("5xx"));
+        assert!(Convention::GRAPHQL_NAME.is_valid("__typename"));
+        assert!(Convention::GRAPHQL_NAME.is_valid("userId"));
+        assert!(Convention::SQL_IDENTIFIER.is_valid("user_id"));
+        assert!(!Convention::SQL_IDENTIFIER.is_valid("_user_id"));
+        assert!(!Convention::SQL_IDENTIFIER.is_valid("userId"));
+    }
+
+    #[test]
+    fn validation() {
+        let snake = Convention::SNAKE_CASE;
+        assert_eq!(snake.validate(""), Err(ConventionError::Empty));
+        assert_eq!(
+            snake.validate("ab_ö"),
+            Err(ConventionError::InvalidChar {
+                char: 'ö',
+                offset: 3
+            })
+        );
+        assert_eq!(
+            snake.validate("€"),
+            Err(ConventionError::InvalidStartChar {
+                char: '€',
+                offset: 0
+            })
+        );
+
+        let checked = snake.validator().check("_http__server").unwrap();
+        assert_eq!(checked, "_http__server");
+        assert_eq!(checked.words().collect::<Vec<_>>(), ["http", "server"]);
+        assert_eq!(checked.convention().name(), "snake_case");
+    }
+}
@@ -0,0 +1,175 @@
+//! Splitting identifiers of any case style into words.
+
+use alloc::string::String;
+
+use crate::SnakeStr;
+
+/// Splits an identifier of any case style into its words.
+///
+/// Word boundaries are:
+/// * anything that is not an ASCII letter or digit (`_`, `-`, space, non-ASCII, …)
+/// * a lower case letter or digit followed by an upper case letter (`helloWorld`)
+/// * the end of an acronym followed by a capitalized word (`HTTPServer`)
+///
+/// Digits stick to the word before them, so `vec3d` is a single word.
+///
+/// The words are returned as slices of the input, with their case untouched.
+pub(crate) struct Words<'a> {
+    string: &'a str,
+    pos: usize,
+}
+
+impl<'a> Words<'a> {
+    pub(crate) fn new(string: &'a str) -> Self {
+        Self { string, pos: 0 }
+    }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        // Non-ASCII characters only consist of bytes >= 0x80, so they are treated as separators,
+        // and every index we slice at is an ASCII byte, i.e. always a char boundary.
+        let bytes = self.string.as_bytes();
+
+        while self.pos < bytes.len() && !bytes[self.pos].is_ascii_alphanumeric() {
+            self.pos += 1;
+        }
+        if self.pos == bytes.len() {
+            return None;
+        }
+
+        let start = self.pos;
+        let mut end = start + 1;
+        while end < bytes.len() {
+            let (prev, cur) = (bytes[end - 1], bytes[end]);
+            if !cur.is_ascii_alphanumeric() {
+                break;
+            }
+            if cur.is_ascii_uppercase() {
+                if prev.is_ascii_lowercase() || prev.is_ascii_digit() {
+                    break; // helloWorld
+                }
+                let next_is_lower = matches!(bytes.get(end + 1), Some(b) if b.is_ascii_lowercase());
+                if prev.is_ascii_uppercase() && next_is_lower {
+                    break; // HTTPServer
+                }
+            }
+            end += 1;
+        }
+
+        self.pos = end;
+        Some(&self.string[start..end])
+    }
+}
+
+/// Lower case all words and join them with underscores.
+///
+/// Returns `None` if there are no words.
+/// If the first word starts with a digit, the result is prefixed with an underscore.
+pub(crate) fn to_snake_case_string(string: &str) -> Option<String> {
+    join_snake_case(Words::new(string))
+}
+
+/// Lower case the words and join them with underscores, like [`to_snake_case_string`].
+pub(crate) fn join_snake_case<'a>(words: impl Iterator<Item = &'a str>) -> Option<String> {
+    let mut out = String::new();
+    for word in words {
+        if out.is_empty() {
+            if word.as_bytes()[0].is_ascii_digit() {
+                out.push('_');
+            }
+        } else {
+            out.push('_');
+        }
+        out.extend(word.chars().map(|c| c.to_ascii_lowercase()));
+    }
+    if out.is_empty() {
+        None
+    } else {
+        Some(out)
+    }
+}
+
+/// Joins the words in camelCase, or in PascalCase if `capitalize_first` is set.
+pub(crate) fn join_camel_case<'a>(
+    words: impl Iterator<Item = &'a str>,
+    capitalize_first: bool,
+) -> String {
+    let mut out = String::new();
+    for word in words {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            if capitalize_first || !out.is_empty() {
+                out.push(first.to_ascii_uppercase());
+            } else {
+                out.push(first.to_ascii_lowercase());
+            }
+            out.extend(chars.map(|c| c.to_ascii_lowercase()));
+        }
+    }
+    out
+}
+
+impl SnakeStr {
+    /// Is this the same identifier as `other`, written in any case style?
+    ///
+    /// Both sides are split into words (like [`crate::SnakeCase::from_any_case`]),
+    /// which are compared ignoring case.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCase;
+    /// let id = SnakeCase::try_from_str("user_id").unwrap();
+    /// assert!(id.eq_any_case("userId"));
+    /// assert!(id.eq_any_case("USER-ID"));
+    /// assert!(!id.eq_any_case("userid"));
+    /// ```
+    ///
+    /// This doesn't allocate.
+    pub fn eq_any_case(&self, other: &str) -> bool {
+        let mut other = Words::new(other);
+        for word in self.words() {
+            match other.next() {
+                Some(other) if other.eq_ignore_ascii_case(word) => {}
+                _ => return false,
+            }
+        }
+        other.next().is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn words() {
+        let words = |s| Words::new(s).collect::<Vec<_>>();
+        assert_eq!(words("hello_world"), ["hello", "world"]);
+        assert_eq!(words("HelloWorld"), ["Hello", "World"]);
+        assert_eq!(words("helloWorld"), ["hello", "World"]);
+        assert_eq!(words("hello-world"), ["hello", "world"]);
+        assert_eq!(words("HELLO WORLD"), ["HELLO", "WORLD"]);
+        assert_eq!(words("HTTPServer"), ["HTTP", "Server"]);
+        assert_eq!(words("myHTTPServer2Go"), ["my", "HTTP", "Server2", "Go"]);
+        assert_eq!(words("vec3d"), ["vec3d"]);
+        assert_eq!(words("  __a--b__  "), ["a", "b"]);
+        assert_eq!(words("Grüße"), ["Gr", "e"]);
+        assert_eq!(words(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn eq_any_case() {
+        let snake = |s| SnakeStr::try_from_str(s).unwrap();
+        assert!(snake("http_server").eq_any_case("HTTPServer"));
+        assert!(snake("_2d").eq_any_case("2d"));
+        assert!(snake("a__b").eq_any_case("a_b"));
+        assert!(!snake("http_server").eq_any_case("http"));
+        assert!(!snake("http").eq_any_case("http_server"));
+        assert!(!snake("_").eq_any_case("x"));
+        assert!(snake("_").eq_any_case("--"));
+    }
+}
@@ -0,0 +1,63 @@
+use alloc::string::String;
+use core::convert::TryFrom as _;
+
+use convert_case::{Case, Casing as _};
+
+use crate::{InvalidSnakeCase, SnakeCase, SnakeStr};
+
+impl SnakeStr {
+    /// Convert to any [`Case`] of the `convert_case` crate, splitting only at underscores.
+    ///
+    /// ```
+    /// # use snake_case::SnakeStr;
+    /// use convert_case::Case;
+    ///
+    /// let snake = SnakeStr::try_from_str("http_server_2").unwrap();
+    /// assert_eq!(snake.convert_to(Case::Title), "Http Server 2");
+    /// assert_eq!(snake.convert_to(Case::Cobol), "HTTP-SERVER-2");
+    /// ```
+    pub fn convert_to(&self, case: Case<'_>) -> String {
+        self.as_str().from_case(Case::Snake).to_case(case)
+    }
+}
+
+impl SnakeCase {
+    /// Convert from any [`Case`] of the `convert_case` crate, splitting words where that case does.
+    ///
+    /// Fails if the result isn't snake_case by the rules of this crate,
+    /// e.g. because it starts with a digit or contains non-ASCII letters.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCase;
+    /// use convert_case::Case;
+    ///
+    /// assert_eq!(SnakeCase::try_from_case("User Profile", Case::Title).unwrap(), "user_profile");
+    /// assert!(SnakeCase::try_from_case("3D Model", Case::Title).is_err());
+    /// ```
+    pub fn try_from_case(s: &str, case: Case<'_>) -> Result<SnakeCase, InvalidSnakeCase> {
+        SnakeCase::try_from(s.from_case(case).to_case(Case::Snake))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_case() {
+        let snake = SnakeCase::try_from_str("user_id").unwrap();
+        assert_eq!(snake.convert_to(Case::Pascal), "UserId");
+        assert_eq!(snake.convert_to(Case::Constant), "USER_ID");
+
+        // Only `-` separates words in kebab-case, so the capital letter doesn't:
+        assert_eq!(
+            SnakeCase::try_from_case("user-accountId", Case::Kebab).unwrap(),
+            "user_accountid"
+        );
+        assert_eq!(
+            SnakeCase::try_from_case("userAccountId", Case::Camel).unwrap(),
+            "user_account_id"
+        );
+        assert!(SnakeCase::try_from_case("Größe", Case::Pascal).is_err());
+    }
+}
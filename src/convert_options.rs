@@ -0,0 +1,187 @@
+use alloc::vec::Vec;
+
+use crate::{
+    convert::{self, Words},
+    AcronymSet, InvalidSnakeCase, SnakeCase,
+};
+
+/// Where digits start or end words when converting to snake_case, see [`ConvertOptions::digits`].
+///
+/// | input    | `Camel`  | `Never` | `Before`  | `After`  | `Both`    |
+/// |----------|----------|---------|-----------|----------|-----------|
+/// | `vec3d`  | `vec3d`  | `vec3d` | `vec_3d`  | `vec3_d` | `vec_3_d` |
+/// | `Vec3D`  | `vec3_d` | `vec3d` | `vec_3_d` | `vec3_d` | `vec_3_d` |
+/// | `vec_3d` | `vec_3d` | `vec_3d`| `vec_3d`  | `vec_3_d`| `vec_3_d` |
+///
+/// An upper case letter after a digit starts a new word in all but [`Self::Never`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum DigitBoundary {
+    /// Digits stick to the word before them. This is what [`SnakeCase::from_any_case`] does.
+    #[default]
+    Camel,
+
+    /// Digits never start or end a word, not even before an upper case letter.
+    Never,
+
+    /// Digits start a new word.
+    Before,
+
+    /// Digits end a word.
+    After,
+
+    /// Digits are words of their own.
+    Both,
+}
+
+/// Options for converting arbitrary identifiers to snake_case.
+///
+/// ```
+/// # use snake_case::{AcronymSet, ConvertOptions, DigitBoundary};
+/// const OPTIONS: ConvertOptions<'static> = ConvertOptions::new()
+///     .acronyms(AcronymSet::new(&["api", "http"]))
+///     .digits(DigitBoundary::Before);
+///
+/// assert_eq!(OPTIONS.to_snake_case("HTTPAPIVersion2").unwrap(), "http_api_version_2");
+/// assert_eq!(OPTIONS.to_snake_case("sha256Sum").unwrap(), "sha_256_sum");
+/// ```
+///
+/// The defaults are the same as [`SnakeCase::from_any_case`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ConvertOptions<'a> {
+    acronyms: AcronymSet<'a>,
+    digits: DigitBoundary,
+}
+
+impl<'a> ConvertOptions<'a> {
+    pub const fn new() -> Self {
+        Self {
+            acronyms: AcronymSet::new(&[]),
+            digits: DigitBoundary::Camel,
+        }
+    }
+
+    /// Split runs of capitals into these, see [`SnakeCase::from_any_case_with`].
+    pub const fn acronyms(mut self, acronyms: AcronymSet<'a>) -> Self {
+        self.acronyms = acronyms;
+        self
+    }
+
+    pub const fn digits(mut self, digits: DigitBoundary) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Fails with [`InvalidSnakeCase::Empty`] if the input contains no ASCII letters or digits.
+    pub fn to_snake_case(&self, s: &str) -> Result<SnakeCase, InvalidSnakeCase> {
+        let mut words: Vec<&str> = Vec::new();
+        for word in Words::new(s) {
+            match words.last_mut() {
+                // `Words` splits `Vec3D` into `Vec3` and `D`:
+                Some(prev)
+                    if self.digits == DigitBoundary::Never
+                        && ends_with_digit(prev)
+                        && follows(s, prev, word) =>
+                {
+                    let start = offset(s, prev);
+                    *prev = &s[start..offset(s, word) + word.len()];
+                }
+                _ => words.push(word),
+            }
+        }
+
+        let mut split = Vec::with_capacity(words.len());
+        for word in words {
+            let all_caps = word.bytes().all(|b| b.is_ascii_uppercase());
+            if !(all_caps && self.acronyms.split(word, &mut split)) {
+                split.push(word);
+            }
+        }
+
+        let mut out = Vec::with_capacity(split.len());
+        for word in split {
+            split_digits(word, self.digits, &mut out);
+        }
+        convert::join_snake_case(out.into_iter())
+            .map(SnakeCase::from_string_unchecked)
+            .ok_or(InvalidSnakeCase::Empty)
+    }
+}
+
+fn ends_with_digit(word: &str) -> bool {
+    matches!(word.bytes().last(), Some(b) if b.is_ascii_digit())
+}
+
+/// The byte offset of `word` in `s`, which it is a slice of.
+fn offset(s: &str, word: &str) -> usize {
+    word.as_ptr() as usize - s.as_ptr() as usize
+}
+
+/// Does `next` come right after `prev` in `s`, without a separator?
+fn follows(s: &str, prev: &str, next: &str) -> bool {
+    offset(s, prev) + prev.len() == offset(s, next)
+}
+
+fn split_digits<'w>(word: &'w str, digits: DigitBoundary, out: &mut Vec<&'w str>) {
+    let bytes = word.as_bytes();
+    let mut start = 0;
+    for i in 1..bytes.len() {
+        let (prev, cur) = (bytes[i - 1].is_ascii_digit(), bytes[i].is_ascii_digit());
+        let boundary = match digits {
+            DigitBoundary::Camel | DigitBoundary::Never => false,
+            DigitBoundary::Before => !prev && cur,
+            DigitBoundary::After => prev && !cur,
+            DigitBoundary::Both => prev != cur,
+        };
+        if boundary {
+            out.push(&word[start..i]);
+            start = i;
+        }
+    }
+    out.push(&word[start..]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digit_boundaries() {
+        let convert = |s, digits| {
+            ConvertOptions::new()
+                .digits(digits)
+                .to_snake_case(s)
+                .unwrap()
+                .into_string()
+        };
+        // The table in the docs:
+        for (input, expected) in [
+            ("vec3d", ["vec3d", "vec3d", "vec_3d", "vec3_d", "vec_3_d"]),
+            ("Vec3D", ["vec3_d", "vec3d", "vec_3_d", "vec3_d", "vec_3_d"]),
+            (
+                "vec_3d",
+                ["vec_3d", "vec_3d", "vec_3d", "vec_3_d", "vec_3_d"],
+            ),
+        ] {
+            let digits = [
+                DigitBoundary::Camel,
+                DigitBoundary::Never,
+                DigitBoundary::Before,
+                DigitBoundary::After,
+                DigitBoundary::Both,
+            ];
+            for (digits, expected) in digits.iter().zip(expected) {
+                assert_eq!(convert(input, *digits), expected, "{} {:?}", input, digits);
+            }
+        }
+
+        assert_eq!(convert("2fast", DigitBoundary::Before), "_2fast");
+        assert_eq!(convert("2fast", DigitBoundary::Both), "_2_fast");
+        assert_eq!(convert("HTTP2Server", DigitBoundary::Never), "http2server");
+        assert_eq!(
+            convert("myHTTPServer2Go", DigitBoundary::Camel),
+            SnakeCase::from_any_case("myHTTPServer2Go")
+                .unwrap()
+                .as_str()
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use defmt::{Format, Formatter};
+
+use crate::{SnakeCaseArray, SnakeCaseRef, SnakeStr};
+
+impl Format for SnakeStr {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.as_str());
+    }
+}
+
+impl Format for SnakeCaseRef<'_> {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.as_str());
+    }
+}
+
+impl<const N: usize> Format for SnakeCaseArray<N> {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.as_str());
+    }
+}
@@ -0,0 +1,52 @@
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql},
+    serialize::{self, Output, ToSql},
+    sql_types::Text,
+};
+
+use crate::SnakeCase;
+
+impl<DB: Backend> ToSql<Text, DB> for SnakeCase
+where
+    str: ToSql<Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.as_str().to_sql(out)
+    }
+}
+
+/// Fails if the column is not valid snake_case.
+impl<DB: Backend> FromSql<Text, DB> for SnakeCase
+where
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let string = String::from_sql(bytes)?;
+        Ok(SnakeCase::try_from_string(string)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::{Connection as _, IntoSql as _, RunQueryDsl as _, SqliteConnection};
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+
+        let name = SnakeCase::try_from_str("hello_world").unwrap();
+        let selected: SnakeCase = diesel::select(name.clone().into_sql::<Text>())
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(selected, name);
+
+        let err = diesel::select("Hello".into_sql::<Text>())
+            .get_result::<SnakeCase>(&mut conn)
+            .unwrap_err();
+        // The `InvalidSnakeCase` is wrapped in diesel's own errors:
+        assert!(format!("{:?}", err).contains("UppercaseChar"), "{:?}", err);
+    }
+}
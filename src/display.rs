@@ -0,0 +1,207 @@
+use core::fmt::{self, Write as _};
+
+use crate::SnakeStr;
+
+#[derive(Clone, Copy)]
+enum Style {
+    Camel,
+    Pascal,
+    Screaming,
+    Kebab,
+}
+
+/// Writes a snake_case string in another case style, converting it while formatting.
+struct DisplayCase<'a> {
+    snake: &'a SnakeStr,
+    style: Style,
+}
+
+impl DisplayCase<'_> {
+    /// The length of the output, in bytes (and since it is all ASCII, in chars).
+    fn len(&self) -> usize {
+        match self.style {
+            Style::Camel | Style::Pascal => self.snake.bytes().filter(|&b| b != b'_').count(),
+            Style::Screaming | Style::Kebab => self.snake.len(),
+        }
+    }
+
+    fn write_to(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        match self.style {
+            Style::Camel | Style::Pascal => {
+                // Same as `convert::join_camel_case`:
+                let mut first_word = true;
+                for word in self.snake.words() {
+                    let mut chars = word.chars();
+                    if let Some(first) = chars.next() {
+                        if first_word && matches!(self.style, Style::Camel) {
+                            out.write_char(first)?;
+                        } else {
+                            out.write_char(first.to_ascii_uppercase())?;
+                        }
+                        out.write_str(chars.as_str())?;
+                    }
+                    first_word = false;
+                }
+                Ok(())
+            }
+            Style::Screaming => {
+                for c in self.snake.chars() {
+                    out.write_char(c.to_ascii_uppercase())?;
+                }
+                Ok(())
+            }
+            Style::Kebab => {
+                for c in self.snake.chars() {
+                    out.write_char(if c == '_' { '-' } else { c })?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Supports width, fill, alignment and precision, just like formatting a `str`.
+impl fmt::Display for DisplayCase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            return self.write_to(f);
+        }
+
+        let len = match f.precision() {
+            Some(precision) => self.len().min(precision),
+            None => self.len(),
+        };
+        let padding = f.width().unwrap_or(0).saturating_sub(len);
+        let (before, after) = match f.align() {
+            None | Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        };
+
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        self.write_to(&mut Truncated {
+            out: &mut *f,
+            remaining: len,
+        })?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+/// Drops everything after the first `remaining` bytes.
+struct Truncated<W> {
+    out: W,
+    remaining: usize,
+}
+
+impl<W: fmt::Write> fmt::Write for Truncated<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // Everything we write is ASCII, so any byte offset is a char boundary.
+        let s = &s[..s.len().min(self.remaining)];
+        self.remaining -= s.len();
+        self.out.write_str(s)
+    }
+}
+
+/// Formatting in other case styles, without allocating.
+///
+/// These write the same as the corresponding `to_*` conversions,
+/// but without validating the result (so `_42` is displayed as camelCase `42`).
+///
+/// ```
+/// # use snake_case::SnakeCaseRef;
+/// let id = SnakeCaseRef::try_from_str("http_server_2").unwrap();
+/// assert_eq!(id.display_camel().to_string(), "httpServer2");
+/// assert_eq!(id.display_pascal().to_string(), "HttpServer2");
+/// assert_eq!(id.display_screaming().to_string(), "HTTP_SERVER_2");
+/// assert_eq!(id.display_kebab().to_string(), "http-server-2");
+/// ```
+impl SnakeStr {
+    /// `my_http_server` -> `myHttpServer`
+    pub fn display_camel(&self) -> impl fmt::Display + '_ {
+        DisplayCase {
+            snake: self,
+            style: Style::Camel,
+        }
+    }
+
+    /// `my_http_server` -> `MyHttpServer`
+    pub fn display_pascal(&self) -> impl fmt::Display + '_ {
+        DisplayCase {
+            snake: self,
+            style: Style::Pascal,
+        }
+    }
+
+    /// `my_http_server` -> `MY_HTTP_SERVER`
+    pub fn display_screaming(&self) -> impl fmt::Display + '_ {
+        DisplayCase {
+            snake: self,
+            style: Style::Screaming,
+        }
+    }
+
+    /// `my_http_server` -> `my-http-server`
+    pub fn display_kebab(&self) -> impl fmt::Display + '_ {
+        DisplayCase {
+            snake: self,
+            style: Style::Kebab,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::{format, string::ToString as _};
+    use core::convert::TryFrom;
+
+    use crate::{CamelCase, SnakeCase};
+
+    #[test]
+    fn same_as_conversions() {
+        for s in ["a", "hello_world", "_private", "x__y_2d", "vec3_"] {
+            let snake = SnakeCase::try_from_str(s).unwrap();
+            assert_eq!(
+                snake.display_screaming().to_string(),
+                snake.to_screaming().as_str()
+            );
+            if let Ok(camel) = CamelCase::try_from(&snake) {
+                assert_eq!(snake.display_camel().to_string(), camel.as_str());
+            }
+            if let Ok(pascal) = snake.to_pascal_case() {
+                assert_eq!(snake.display_pascal().to_string(), pascal.as_str());
+            }
+            if let Ok(kebab) = snake.to_kebab_case() {
+                assert_eq!(snake.display_kebab().to_string(), kebab.as_str());
+            }
+        }
+    }
+
+    #[test]
+    fn padding() {
+        let snake = SnakeCase::try_from_str("max_depth").unwrap();
+        assert_eq!(format!("[{:<12}]", snake), "[max_depth   ]");
+        assert_eq!(format!("[{:>width$}]", snake, width = 12), "[   max_depth]");
+        assert_eq!(
+            format!("[{:<12}]", snake.display_pascal()),
+            "[MaxDepth    ]"
+        );
+        assert_eq!(format!("[{:>12}]", snake.display_camel()), "[    maxDepth]");
+        assert_eq!(
+            format!("[{:*^12}]", snake.display_kebab()),
+            "[*max-depth**]"
+        );
+        assert_eq!(
+            format!("[{:12}]", snake.display_screaming()),
+            "[MAX_DEPTH   ]"
+        );
+        assert_eq!(format!("[{:4}]", snake.display_screaming()), "[MAX_DEPTH]");
+        assert_eq!(format!("[{:.5}]", snake.display_pascal()), "[MaxDe]");
+        assert_eq!(format!("[{:>7.5}]", snake.display_camel()), "[  maxDe]");
+    }
+}
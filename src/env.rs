@@ -0,0 +1,65 @@
+//! Reading environment variables with snake_case keys.
+//!
+//! Config systems usually name their keys `database_url`, but the environment variables `DATABASE_URL`.
+//! These helpers do the conversion, so the keys can stay typed:
+//!
+//! ```
+//! # use snake_case::SnakeCaseRef;
+//! std::env::set_var("MY_APP_LOG_LEVEL", "debug");
+//!
+//! let key = SnakeCaseRef::try_from_str("my_app_log_level").unwrap();
+//! assert_eq!(snake_case::env::var_snake(&key).unwrap(), "debug");
+//!
+//! let (key, value) = snake_case::env::vars_snake()
+//!     .find(|(key, _)| key == "my_app_log_level")
+//!     .unwrap();
+//! assert_eq!(key.to_env_key(), "MY_APP_LOG_LEVEL");
+//! ```
+
+use std::{
+    env::{self, VarError},
+    ffi::OsString,
+    string::String,
+};
+
+use crate::{ScreamingSnakeCaseRef, SnakeCase, SnakeCaseRef};
+
+/// Read the environment variable for this key, e.g. `DATABASE_URL` for `database_url`.
+///
+/// Errors like [`std::env::var`].
+pub fn var_snake(key: &SnakeCaseRef<'_>) -> Result<String, VarError> {
+    env::var(key.to_env_key().as_str())
+}
+
+/// Like [`var_snake`], but without requiring the value to be valid unicode.
+pub fn var_os_snake(key: &SnakeCaseRef<'_>) -> Option<OsString> {
+    env::var_os(key.to_env_key().as_str())
+}
+
+/// All environment variables with SCREAMING_SNAKE_CASE names (and unicode values), with snake_case keys.
+///
+/// Everything else (like `Path` on Windows) is skipped.
+pub fn vars_snake() -> impl Iterator<Item = (SnakeCase, String)> {
+    env::vars_os().filter_map(|(key, value)| {
+        let key = key.into_string().ok()?;
+        let key = ScreamingSnakeCaseRef::try_from_str(&key).ok()?.to_snake();
+        Some((key, value.into_string().ok()?))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env() {
+        env::set_var("SNAKE_CASE_ENV_TEST", "1");
+        let key = SnakeCaseRef::try_from_str("snake_case_env_test").unwrap();
+        assert_eq!(var_snake(&key), Ok("1".into()));
+        assert_eq!(var_os_snake(&key), Some("1".into()));
+        assert!(vars_snake().any(|(k, v)| k == "snake_case_env_test" && v == "1"));
+
+        let missing = SnakeCaseRef::try_from_str("snake_case_env_test_missing").unwrap();
+        assert_eq!(var_snake(&missing), Err(VarError::NotPresent));
+    }
+}
@@ -0,0 +1,43 @@
+//! `hashbrown` and `indexmap` both look up keys through [`equivalent::Equivalent`],
+//! so these impls work with both.
+
+use equivalent::Equivalent;
+
+use crate::{SnakeCase, SnakeCaseRef};
+
+/// Look up a map keyed by [`SnakeCase`] with a [`SnakeCaseRef`], without allocating.
+///
+/// (`&SnakeStr` and `&str` already work, through `Borrow`.)
+impl<S: AsRef<str>> Equivalent<SnakeCase<S>> for SnakeCaseRef<'_> {
+    fn equivalent(&self, key: &SnakeCase<S>) -> bool {
+        self.as_str() == key.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::hash::{BuildHasher, Hash};
+    use std::collections::hash_map::RandomState;
+
+    use super::*;
+    use crate::ArcSnakeCase;
+
+    fn hash(state: &RandomState, value: impl Hash) -> u64 {
+        state.hash_one(value)
+    }
+
+    #[test]
+    fn equivalent() {
+        let key = SnakeCaseRef::try_from_str("user_id").unwrap();
+        let owned = SnakeCase::try_from_str("user_id").unwrap();
+        let arc = ArcSnakeCase::from(key);
+        assert!(key.equivalent(&owned));
+        assert!(key.equivalent(&arc));
+        assert!(!SnakeCaseRef::try_from_str("id").unwrap().equivalent(&owned));
+
+        // The `Equivalent` contract: equivalent keys hash the same.
+        let state = RandomState::new();
+        assert_eq!(hash(&state, key), hash(&state, &owned));
+        assert_eq!(hash(&state, key), hash(&state, &arc));
+    }
+}
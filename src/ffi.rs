@@ -0,0 +1,147 @@
+//! A C API, so code in other languages can use the same validation rules.
+//!
+//! Strings are passed as a pointer and a length in bytes, and need not be NUL-terminated or valid UTF-8.
+//! The types are `#[repr(C)]`, so `cbindgen` can generate a header from this module:
+//!
+//! ```c
+//! const char *name = "userId";
+//! char out[64];
+//! size_t out_len;
+//! if (!snake_case_is_valid((const uint8_t *)name, strlen(name))) {
+//!     snake_case_convert_lossy((const uint8_t *)name, strlen(name), (uint8_t *)out, sizeof out, &out_len);
+//! }
+//! ```
+
+use alloc::string::String;
+
+use crate::{ErrorKind, SnakeCaseStrExt as _};
+
+/// The result of the `snake_case_*` functions.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SnakeCaseStatus {
+    Ok = 0,
+    /// The string is empty.
+    Empty = 1,
+    /// The string doesn't start with a lower case letter or underscore.
+    InvalidStartChar = 2,
+    /// The string contains something other than lower case letters, digits and underscores.
+    InvalidChar = 3,
+    /// The string contains an upper case letter.
+    UppercaseChar = 4,
+    /// The output buffer is too small.
+    BufferTooSmall = 5,
+    /// A pointer argument was null.
+    NullPointer = 6,
+}
+
+impl From<ErrorKind> for SnakeCaseStatus {
+    fn from(kind: ErrorKind) -> Self {
+        match kind {
+            ErrorKind::Empty => Self::Empty,
+            ErrorKind::InvalidStartChar => Self::InvalidStartChar,
+            ErrorKind::InvalidChar => Self::InvalidChar,
+            ErrorKind::UppercaseChar => Self::UppercaseChar,
+        }
+    }
+}
+
+/// Returns `true` if the `len` bytes at `string` are snake_case.
+///
+/// # Safety
+/// `string` must point to `len` readable bytes, or be null (which returns `false`).
+#[no_mangle]
+pub unsafe extern "C" fn snake_case_is_valid(string: *const u8, len: usize) -> bool {
+    !string.is_null() && crate::is_snake_case_bytes(core::slice::from_raw_parts(string, len))
+}
+
+/// Like [`snake_case_is_valid`], but tells you what is wrong,
+/// and writes the byte offset of the offending character to `offset` (if not null).
+///
+/// # Safety
+/// `string` must point to `len` readable bytes, and `offset` must be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn snake_case_validate(
+    string: *const u8,
+    len: usize,
+    offset: *mut usize,
+) -> SnakeCaseStatus {
+    if string.is_null() {
+        return SnakeCaseStatus::NullPointer;
+    }
+    match crate::validate_bytes(core::slice::from_raw_parts(string, len)) {
+        Ok(()) => SnakeCaseStatus::Ok,
+        Err(err) => {
+            if !offset.is_null() {
+                *offset = err.offset();
+            }
+            err.kind().into()
+        }
+    }
+}
+
+/// Converts the `len` bytes at `string` to snake_case like [`crate::SnakeCaseStrExt::to_snake_case_lossy`],
+/// writing the result (without a NUL terminator) to `out` and its length to `out_len`.
+///
+/// If the result doesn't fit in `out_capacity` bytes, nothing is written to `out`,
+/// `out_len` is set to the needed capacity, and [`SnakeCaseStatus::BufferTooSmall`] is returned.
+/// Invalid UTF-8 is treated as a word separator.
+///
+/// # Safety
+/// `string` must point to `len` readable bytes, `out` to `out_capacity` writable bytes,
+/// and `out_len` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn snake_case_convert_lossy(
+    string: *const u8,
+    len: usize,
+    out: *mut u8,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> SnakeCaseStatus {
+    if string.is_null() || out.is_null() || out_len.is_null() {
+        return SnakeCaseStatus::NullPointer;
+    }
+    let input = String::from_utf8_lossy(core::slice::from_raw_parts(string, len));
+    let snake = input.to_snake_case_lossy();
+    *out_len = snake.len();
+    if snake.len() > out_capacity {
+        return SnakeCaseStatus::BufferTooSmall;
+    }
+    core::ptr::copy_nonoverlapping(snake.as_str().as_ptr(), out, snake.len());
+    SnakeCaseStatus::Ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ffi() {
+        unsafe {
+            assert!(snake_case_is_valid(b"user_id".as_ptr(), 7));
+            assert!(!snake_case_is_valid(b"user_id".as_ptr(), 0));
+            assert!(!snake_case_is_valid(core::ptr::null(), 0));
+
+            let mut offset = 0;
+            assert_eq!(
+                snake_case_validate(b"user\xFFid".as_ptr(), 7, &mut offset),
+                SnakeCaseStatus::InvalidChar
+            );
+            assert_eq!(offset, 4);
+
+            let mut out = [0_u8; 8];
+            let mut out_len = 0;
+            let input = b"userId";
+            let status =
+                snake_case_convert_lossy(input.as_ptr(), 6, out.as_mut_ptr(), 8, &mut out_len);
+            assert_eq!(status, SnakeCaseStatus::Ok);
+            assert_eq!(&out[..out_len], b"user_id");
+
+            let input = b"HTTPServerName";
+            let status =
+                snake_case_convert_lossy(input.as_ptr(), 14, out.as_mut_ptr(), 8, &mut out_len);
+            assert_eq!(status, SnakeCaseStatus::BufferTooSmall);
+            assert_eq!(out_len, "http_server_name".len());
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{SnakeCase, SnakeCaseRef};
+
+/// Group identifiers by their first `depth` words, for building a hierarchy out of a flat list.
+///
+/// ```
+/// # use snake_case::{group_by_prefix, SnakeCaseRef};
+/// let settings = ["window_width", "window_height", "font_size", "window_title_bar_color"];
+/// let settings = settings.iter().map(|s| SnakeCaseRef::try_from_str(s).unwrap());
+///
+/// let groups = group_by_prefix(settings, 1);
+/// assert_eq!(groups.keys().collect::<Vec<_>>(), ["font", "window"]);
+/// assert_eq!(groups["window"], ["window_width", "window_height", "window_title_bar_color"]);
+/// ```
+///
+/// The keys are cut like [`SnakeCase::truncate_words`], so identifiers with at most `depth` words
+/// are grouped under themselves, and a `depth` of zero is treated as one.
+/// Within a group, the identifiers stay in the order they came in.
+pub fn group_by_prefix<'a>(
+    idents: impl Iterator<Item = SnakeCaseRef<'a>>,
+    depth: usize,
+) -> BTreeMap<SnakeCase, Vec<SnakeCaseRef<'a>>> {
+    let mut groups: BTreeMap<SnakeCase, Vec<SnakeCaseRef<'a>>> = BTreeMap::new();
+    for ident in idents {
+        let mut key = ident.to_owned();
+        key.truncate_words(depth);
+        groups.entry(key).or_default().push(ident);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn group_by_prefix() {
+        let idents = ["a_b_c", "a_b_d", "a_c", "a", "_a_b"];
+        let idents = || {
+            idents
+                .iter()
+                .map(|s| SnakeCaseRef::try_from_str(s).unwrap())
+        };
+
+        let groups = super::group_by_prefix(idents(), 2);
+        let groups: Vec<(&str, Vec<&str>)> = groups
+            .iter()
+            .map(|(key, idents)| (key.as_str(), idents.iter().map(|s| s.as_str()).collect()))
+            .collect();
+        assert_eq!(
+            groups,
+            [
+                ("_a_b", vec!["_a_b"]),
+                ("a", vec!["a"]),
+                ("a_b", vec!["a_b_c", "a_b_d"]),
+                ("a_c", vec!["a_c"]),
+            ]
+        );
+
+        assert_eq!(super::group_by_prefix(idents(), 0).len(), 2);
+    }
+}
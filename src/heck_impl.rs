@@ -0,0 +1,73 @@
+use alloc::string::ToString as _;
+use core::convert::TryFrom;
+
+use heck::{AsSnakeCase, ToSnakeCase};
+
+use crate::{InvalidSnakeCase, SnakeCase, SnakeCaseRef, SnakeStr};
+
+/// Already snake_case, so this just makes an owned copy.
+impl ToSnakeCase for SnakeStr {
+    fn to_snake_case(&self) -> SnakeCase {
+        self.into()
+    }
+}
+
+/// Already snake_case, so this is just a clone.
+impl ToSnakeCase for SnakeCase {
+    fn to_snake_case(&self) -> SnakeCase {
+        self.clone()
+    }
+}
+
+/// Already snake_case, so this is just a copy.
+impl<'a> ToSnakeCase for SnakeCaseRef<'a> {
+    fn to_snake_case(&self) -> SnakeCaseRef<'a> {
+        *self
+    }
+}
+
+/// Validates the output of `heck`, which isn't always snake_case by the rules of this crate:
+/// it keeps non-ASCII letters, and leading digits.
+///
+/// ```
+/// # use snake_case::SnakeCase;
+/// use std::convert::TryFrom as _;
+///
+/// let snake = SnakeCase::try_from(heck::AsSnakeCase("HTTPServer")).unwrap();
+/// assert_eq!(snake, "http_server");
+/// assert!(SnakeCase::try_from(heck::AsSnakeCase("3D model")).is_err());
+/// ```
+impl<T: AsRef<str>> TryFrom<AsSnakeCase<T>> for SnakeCase {
+    type Error = InvalidSnakeCase;
+
+    fn try_from(snake: AsSnakeCase<T>) -> Result<Self, Self::Error> {
+        SnakeCase::try_from(snake.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heck::ToSnekCase as _;
+
+    use super::*;
+
+    #[test]
+    fn heck() {
+        let snake = SnakeCase::try_from_str("user_id").unwrap();
+        assert_eq!(ToSnakeCase::to_snake_case(&snake), snake);
+        assert_eq!(snake.as_ref().to_snek_case(), "user_id");
+        assert_eq!(ToSnakeCase::to_snake_case(&*snake), snake);
+
+        assert_eq!(
+            SnakeCase::try_from(AsSnakeCase("userId")).unwrap(),
+            "user_id"
+        );
+        assert_eq!(
+            SnakeCase::try_from(AsSnakeCase("Größe")).unwrap_err(),
+            InvalidSnakeCase::InvalidChar {
+                char: 'ö',
+                offset: 2
+            }
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use alloc::{borrow::ToOwned as _, string::String};
+
+use crate::{SnakeCase, SnakeStr};
+
+/// English plurals, for mapping between table names and type names (`user_account` ⇄ `user_accounts`).
+///
+/// Only the last word is changed, so `line_item` becomes `line_items` rather than `lines_items`:
+///
+/// ```
+/// # use snake_case::SnakeStr;
+/// let snake = |s| SnakeStr::try_from_str(s).unwrap();
+/// assert_eq!(snake("user_account").pluralize(), "user_accounts");
+/// assert_eq!(snake("order_status").pluralize(), "order_statuses");
+/// assert_eq!(snake("child_category").pluralize(), "child_categories");
+/// assert_eq!(snake("team_people").singularize(), "team_person");
+/// assert_eq!(snake("user_metadata").singularize(), "user_metadata");
+/// ```
+///
+/// The rules cover regular English words and a list of common irregular and uncountable ones;
+/// anything like a proper inflection dictionary is out of scope.
+/// Words that are all digits are left alone, and so are any trailing underscores (`type_` ⇄ `types_`).
+impl SnakeStr {
+    /// Pluralize the last word, which is assumed to be singular.
+    pub fn pluralize(&self) -> SnakeCase {
+        self.map_last_word(plural)
+    }
+
+    /// Singularize the last word. Words that already look singular are left as they are.
+    pub fn singularize(&self) -> SnakeCase {
+        self.map_last_word(singular)
+    }
+
+    fn map_last_word(&self, f: impl FnOnce(&str) -> Option<String>) -> SnakeCase {
+        let s = self.as_str();
+        let trimmed = s.trim_end_matches('_');
+        let start = trimmed.rfind('_').map_or(0, |i| i + 1);
+        let word = &trimmed[start..];
+        match f(word) {
+            Some(new_word) if !word.bytes().all(|b| b.is_ascii_digit()) => {
+                let mut string = String::with_capacity(s.len() + 2);
+                string.push_str(&s[..start]);
+                string.push_str(&new_word);
+                string.push_str(&s[trimmed.len()..]);
+                // Only lower case letters were changed, after the first letter of a word:
+                debug_assert!(crate::is_snake_case(&string));
+                SnakeCase::from_string_unchecked(string)
+            }
+            _ => self.to_owned(),
+        }
+    }
+}
+
+/// (singular, plural)
+const IRREGULAR: &[(&str, &str)] = &[
+    ("child", "children"),
+    ("cookie", "cookies"),
+    ("criterion", "criteria"),
+    ("foot", "feet"),
+    ("goose", "geese"),
+    ("index", "indices"),
+    ("man", "men"),
+    ("matrix", "matrices"),
+    ("medium", "media"),
+    ("mouse", "mice"),
+    ("movie", "movies"),
+    ("ox", "oxen"),
+    ("person", "people"),
+    ("quiz", "quizzes"),
+    ("tooth", "teeth"),
+    ("vertex", "vertices"),
+    ("woman", "women"),
+];
+
+/// The same in singular and plural.
+const UNCOUNTABLE: &[&str] = &[
+    "data",
+    "deer",
+    "equipment",
+    "feedback",
+    "fish",
+    "information",
+    "metadata",
+    "money",
+    "news",
+    "rice",
+    "series",
+    "sheep",
+    "species",
+    "staff",
+];
+
+/// Singulars ending in `f` or `fe` that become `ves`.
+const F_TO_VES: &[&str] = &[
+    "calf", "half", "knife", "leaf", "life", "loaf", "self", "shelf", "thief", "wife", "wolf",
+];
+
+/// Singulars ending in `o` that take `es`.
+const O_TO_OES: &[&str] = &["echo", "hero", "potato", "tomato", "veto"];
+
+/// Singulars ending in `s` that take `es` (and are not `ss`, `us` or `is`).
+const S_TO_SES: &[&str] = &["alias", "bias", "canvas", "gas"];
+
+/// Stems of singulars ending in `is`, for recognizing their plurals.
+const IS_TO_ES: &[&str] = &[
+    "analys",
+    "cris",
+    "diagnos",
+    "hypothes",
+    "parenthes",
+    "synops",
+    "thes",
+];
+
+fn plural(word: &str) -> Option<String> {
+    if UNCOUNTABLE.contains(&word) {
+        return None;
+    }
+    if let Some((_, plural)) = IRREGULAR.iter().find(|(singular, _)| *singular == word) {
+        return Some((*plural).into());
+    }
+    if F_TO_VES.contains(&word) {
+        let stem = word.strip_suffix("fe").or_else(|| word.strip_suffix('f'))?;
+        return Some([stem, "ves"].concat());
+    }
+    if let Some(stem) = word.strip_suffix("is") {
+        return Some([stem, "es"].concat()); // analysis -> analyses
+    }
+    if let Some(stem) = word.strip_suffix('y') {
+        if stem.ends_with(|c: char| !is_vowel(c)) {
+            return Some([stem, "ies"].concat());
+        }
+    }
+    let es = ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|suffix| word.ends_with(suffix))
+        || O_TO_OES.contains(&word);
+    Some([word, if es { "es" } else { "s" }].concat())
+}
+
+fn singular(word: &str) -> Option<String> {
+    if UNCOUNTABLE.contains(&word) {
+        return None;
+    }
+    if let Some((singular, _)) = IRREGULAR.iter().find(|(_, plural)| *plural == word) {
+        return Some((*singular).into());
+    }
+    if IRREGULAR.iter().any(|(singular, _)| *singular == word) {
+        return None;
+    }
+    if let Some(stem) = word.strip_suffix("ves") {
+        let f = [stem, "f"].concat();
+        let fe = [stem, "fe"].concat();
+        if let Some(singular) = F_TO_VES.iter().find(|&&s| s == f || s == fe) {
+            return Some((*singular).into());
+        }
+    }
+    if let Some(stem) = word.strip_suffix("ies") {
+        if !stem.is_empty() {
+            return Some([stem, "y"].concat());
+        }
+    }
+    if let Some(stem) = word.strip_suffix("es") {
+        if ["ss", "us", "x", "z", "ch", "sh"]
+            .iter()
+            .any(|suffix| stem.ends_with(suffix))
+            || O_TO_OES.contains(&stem)
+            || S_TO_SES.contains(&stem)
+        {
+            return Some(stem.into()); // classes, statuses, boxes, matches, heroes
+        }
+        if IS_TO_ES.contains(&stem) {
+            return Some([stem, "is"].concat()); // analyses
+        }
+    }
+    if ["ss", "us", "is"]
+        .iter()
+        .any(|suffix| word.ends_with(suffix))
+    {
+        return None; // already singular: class, status, analysis
+    }
+    word.strip_suffix('s')
+        .filter(|stem| !stem.is_empty())
+        .map(Into::into)
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snake(s: &str) -> &SnakeStr {
+        SnakeStr::try_from_str(s).unwrap()
+    }
+
+    #[test]
+    fn pluralize_and_singularize() {
+        let pairs = [
+            ("user", "users"),
+            ("user_account", "user_accounts"),
+            ("order_status", "order_statuses"),
+            ("category", "categories"),
+            ("day", "days"),
+            ("box", "boxes"),
+            ("batch", "batches"),
+            ("address", "addresses"),
+            ("alias", "aliases"),
+            ("bias", "biases"),
+            ("case", "cases"),
+            ("hero", "heroes"),
+            ("photo", "photos"),
+            ("leaf", "leaves"),
+            ("life", "lives"),
+            ("archive", "archives"),
+            ("database", "databases"),
+            ("analysis", "analyses"),
+            ("team_person", "team_people"),
+            ("child", "children"),
+            ("movie", "movies"),
+            ("news", "news"),
+            ("type_", "types_"),
+            ("_id", "_ids"),
+        ];
+        for (singular, plural) in pairs {
+            assert_eq!(snake(singular).pluralize(), plural);
+            assert_eq!(snake(plural).singularize(), singular);
+        }
+    }
+
+    #[test]
+    fn left_alone() {
+        assert_eq!(snake("table_2").pluralize(), "table_2");
+        assert_eq!(snake("___").pluralize(), "___");
+        assert_eq!(snake("status").singularize(), "status");
+        assert_eq!(snake("person").singularize(), "person");
+        assert_eq!(snake("s").singularize(), "s");
+    }
+}
@@ -0,0 +1,127 @@
+use std::{collections::HashMap, convert::TryFrom, sync::Arc};
+
+use crate::{InvalidSnakeCase, SnakeCaseRef};
+
+/// A handle to a string interned in a [`SnakeCaseInterner`].
+///
+/// Cheap to copy, compare and hash. Use [`SnakeCaseInterner::resolve`] to get the string back.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SnakeSymbol(u32);
+
+impl SnakeSymbol {
+    /// The index of the symbol in its interner, in the order they were interned.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Deduplicates snake_case strings, handing out [`SnakeSymbol`]s for them.
+///
+/// Each distinct string is only stored once, no matter how many times it is interned.
+///
+/// ```
+/// # use snake_case::{SnakeCaseInterner, SnakeCaseRef};
+/// let mut interner = SnakeCaseInterner::new();
+/// let a = interner.try_intern("hello_world").unwrap();
+/// let b = interner.intern(SnakeCaseRef::try_from_str("hello_world").unwrap());
+/// assert_eq!(a, b);
+/// assert_eq!(interner.resolve(a).unwrap(), "hello_world");
+/// ```
+#[derive(Clone, Default)]
+pub struct SnakeCaseInterner {
+    symbols: HashMap<Arc<str>, SnakeSymbol>,
+    strings: Vec<Arc<str>>,
+}
+
+impl SnakeCaseInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the existing symbol for this string, or interns it.
+    ///
+    /// # Panics
+    /// If more than `u32::MAX` distinct strings are interned.
+    pub fn intern(&mut self, snake: SnakeCaseRef<'_>) -> SnakeSymbol {
+        if let Some(symbol) = self.get(snake.as_str()) {
+            return symbol;
+        }
+        let index = u32::try_from(self.strings.len()).expect("Too many interned strings");
+        let symbol = SnakeSymbol(index);
+        let string: Arc<str> = Arc::from(snake.as_str());
+        self.strings.push(string.clone());
+        self.symbols.insert(string, symbol);
+        symbol
+    }
+
+    /// Validates and interns the string.
+    pub fn try_intern(&mut self, s: &str) -> Result<SnakeSymbol, InvalidSnakeCase> {
+        Ok(self.intern(SnakeCaseRef::try_from_str(s)?))
+    }
+
+    /// The symbol of the string, if it has been interned.
+    pub fn get(&self, s: &str) -> Option<SnakeSymbol> {
+        self.symbols.get(s).copied()
+    }
+
+    /// The string of the symbol.
+    ///
+    /// Returns `None` if the symbol is out of range.
+    /// Symbols are just indices, so a symbol from a different interner resolves to an arbitrary string
+    /// (or to `None`): don't mix them up.
+    pub fn resolve(&self, symbol: SnakeSymbol) -> Option<SnakeCaseRef<'_>> {
+        // Only valid snake_case is ever interned.
+        self.strings
+            .get(symbol.index())
+            .map(|string| SnakeCaseRef(string))
+    }
+
+    /// Number of distinct interned strings.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// All interned strings, in the order they were interned.
+    pub fn iter(&self) -> impl Iterator<Item = (SnakeSymbol, SnakeCaseRef<'_>)> {
+        self.strings
+            .iter()
+            .enumerate()
+            .map(|(index, string)| (SnakeSymbol(index as u32), SnakeCaseRef(string)))
+    }
+}
+
+impl std::fmt::Debug for SnakeCaseInterner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.strings.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interner() {
+        let mut interner = SnakeCaseInterner::new();
+        assert!(interner.is_empty());
+
+        let foo = interner.try_intern("foo").unwrap();
+        let bar = interner.try_intern("bar").unwrap();
+        assert_eq!(interner.try_intern("foo").unwrap(), foo);
+        assert_ne!(foo, bar);
+        assert_eq!(interner.len(), 2);
+        assert!(interner.try_intern("Foo").is_err());
+
+        assert_eq!(interner.get("bar"), Some(bar));
+        assert_eq!(interner.get("baz"), None);
+        assert_eq!(interner.resolve(foo).unwrap(), "foo");
+        assert_eq!(interner.resolve(SnakeSymbol(42)), None);
+
+        let all: Vec<_> = interner.iter().map(|(_, s)| s.as_str()).collect();
+        assert_eq!(all, ["foo", "bar"]);
+    }
+}
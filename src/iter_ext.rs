@@ -0,0 +1,99 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{is_snake_case_fast, SnakeCaseRef};
+
+#[cfg(feature = "alloc")]
+use crate::{swar::validate_fast, InvalidSnakeCase};
+
+/// snake_case adaptors for iterators of `&str`.
+///
+/// ```
+/// use snake_case::SnakeCaseIterExt as _;
+///
+/// let header = "id,userName,email";
+/// let columns: Vec<_> = header.split(',').filter_snake_case().collect();
+/// assert_eq!(columns, ["id", "email"]);
+/// ```
+pub trait SnakeCaseIterExt<'a>: Iterator<Item = &'a str> + Sized {
+    /// Skip everything that is not snake_case.
+    fn filter_snake_case(self) -> FilterSnakeCase<Self> {
+        FilterSnakeCase { iter: self }
+    }
+
+    /// Split into the valid snake_case strings and the invalid ones (with what is wrong with them),
+    /// both in the original order.
+    ///
+    /// ```
+    /// use snake_case::{InvalidSnakeCase, SnakeCaseIterExt as _};
+    ///
+    /// let (valid, invalid) = ["id", "Name", "email"].iter().copied().partition_snake_case();
+    /// assert_eq!(valid, ["id", "email"]);
+    /// assert_eq!(invalid, [("Name", InvalidSnakeCase::UppercaseChar { offset: 0 })]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    fn partition_snake_case(self) -> (Vec<SnakeCaseRef<'a>>, Vec<(&'a str, InvalidSnakeCase)>) {
+        let mut valid = Vec::new();
+        let mut invalid = Vec::new();
+        for s in self {
+            match validate_fast(s) {
+                Ok(()) => valid.push(SnakeCaseRef(s)),
+                Err(err) => invalid.push((s, err)),
+            }
+        }
+        (valid, invalid)
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a str>> SnakeCaseIterExt<'a> for I {}
+
+/// See [`SnakeCaseIterExt::filter_snake_case`].
+#[derive(Clone, Debug)]
+pub struct FilterSnakeCase<I> {
+    iter: I,
+}
+
+impl<'a, I: Iterator<Item = &'a str>> Iterator for FilterSnakeCase<I> {
+    type Item = SnakeCaseRef<'a>;
+
+    fn next(&mut self) -> Option<SnakeCaseRef<'a>> {
+        self.iter
+            .by_ref()
+            .find(|s| is_snake_case_fast(s))
+            .map(SnakeCaseRef)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a, I: DoubleEndedIterator<Item = &'a str>> DoubleEndedIterator for FilterSnakeCase<I> {
+    fn next_back(&mut self) -> Option<SnakeCaseRef<'a>> {
+        self.iter
+            .by_ref()
+            .rfind(|s| is_snake_case_fast(s))
+            .map(SnakeCaseRef)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iter_ext() {
+        let names = ["a", "B", "", "c_d", "e-f"];
+        let valid: Vec<_> = names.iter().copied().filter_snake_case().rev().collect();
+        assert_eq!(valid, ["c_d", "a"]);
+
+        let (valid, invalid) = names.iter().copied().partition_snake_case();
+        assert_eq!(valid, ["a", "c_d"]);
+        assert_eq!(
+            invalid.iter().map(|(s, _)| *s).collect::<Vec<_>>(),
+            ["B", "", "e-f"]
+        );
+        assert_eq!(invalid[1].1, InvalidSnakeCase::Empty);
+    }
+}
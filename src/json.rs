@@ -0,0 +1,156 @@
+use serde_json::{Map, Value};
+
+use crate::{is_snake_case, validate, InvalidSnakeCase, SnakeCase};
+
+/// A JSON object key that is not valid snake_case, as found by [`validate_json_keys`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyError {
+    /// [JSON pointer](https://www.rfc-editor.org/rfc/rfc6901) to the offending key, e.g. `/users/0/firstName`.
+    pub pointer: String,
+
+    /// The offending key.
+    pub key: String,
+
+    /// What is wrong with the key.
+    pub error: InvalidSnakeCase,
+}
+
+impl std::fmt::Display for KeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid key {:?} at {}: {}",
+            self.key, self.pointer, self.error
+        )
+    }
+}
+
+impl std::error::Error for KeyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Check that every object key in the value, at any depth, is valid snake_case.
+///
+/// ```
+/// # use snake_case::validate_json_keys;
+/// let value = serde_json::json!({ "users": [{ "first_name": "Ada" }, { "lastName": "Lovelace" }] });
+/// let err = validate_json_keys(&value).unwrap_err();
+/// assert_eq!(err.pointer, "/users/1/lastName");
+/// ```
+///
+/// Returns the first invalid key found, in key order (or in document order if the
+/// `preserve_order` feature of `serde_json` is enabled).
+pub fn validate_json_keys(value: &Value) -> Result<(), KeyError> {
+    validate_keys_at(value, &mut String::new())
+}
+
+fn validate_keys_at(value: &Value, pointer: &mut String) -> Result<(), KeyError> {
+    let len = pointer.len();
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                push_pointer_segment(pointer, key);
+                if let Err(error) = validate(key) {
+                    return Err(KeyError {
+                        pointer: pointer.clone(),
+                        key: key.clone(),
+                        error,
+                    });
+                }
+                validate_keys_at(value, pointer)?;
+                pointer.truncate(len);
+            }
+        }
+        Value::Array(array) => {
+            for (i, value) in array.iter().enumerate() {
+                pointer.push('/');
+                pointer.push_str(&i.to_string());
+                validate_keys_at(value, pointer)?;
+                pointer.truncate(len);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// `~` and `/` are escaped in JSON pointers.
+fn push_pointer_segment(pointer: &mut String, key: &str) {
+    pointer.push('/');
+    pointer.push_str(&key.replace('~', "~0").replace('/', "~1"));
+}
+
+/// Convert every object key in the value, at any depth, to snake_case with [`SnakeCase::from_any_case`].
+///
+/// ```
+/// # use snake_case::snake_case_json_keys;
+/// let value = serde_json::json!({ "userName": "ada", "Tags": [{ "isAdmin": true }] });
+/// assert_eq!(
+///     snake_case_json_keys(value),
+///     serde_json::json!({ "user_name": "ada", "tags": [{ "is_admin": true }] })
+/// );
+/// ```
+///
+/// Keys that are already snake_case (like `_id`) are left as they are,
+/// and so are keys without any ASCII letters or digits.
+/// If a converted key becomes the same as another key of the same object, only one of the values is kept:
+/// the last one in key order (or in document order with `serde_json/preserve_order`).
+pub fn snake_case_json_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| {
+                    let key = if is_snake_case(&key) {
+                        key
+                    } else {
+                        match SnakeCase::from_any_case(&key) {
+                            Ok(snake) => snake.into_string(),
+                            Err(_) => key,
+                        }
+                    };
+                    (key, snake_case_json_keys(value))
+                })
+                .collect::<Map<String, Value>>(),
+        ),
+        Value::Array(array) => Value::Array(array.into_iter().map(snake_case_json_keys).collect()),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn validate_keys() {
+        assert!(validate_json_keys(&json!({ "a": { "b_c": [1, { "d": null }] } })).is_ok());
+        assert!(validate_json_keys(&json!(["notAKey"])).is_ok());
+
+        let err = validate_json_keys(&json!({ "a": [{ "b/Cd": 1 }] })).unwrap_err();
+        assert_eq!(err.pointer, "/a/0/b~1Cd");
+        assert_eq!(err.key, "b/Cd");
+        assert_eq!(
+            err.error,
+            InvalidSnakeCase::InvalidChar {
+                char: '/',
+                offset: 1
+            }
+        );
+    }
+
+    #[test]
+    fn rewrite_keys() {
+        let value = json!({ "HTTPServer": { "maxConns": [{ "Retry-After": 3 }] }, "--": "keep" });
+        assert_eq!(
+            snake_case_json_keys(value),
+            json!({ "http_server": { "max_conns": [{ "retry_after": 3 }] }, "--": "keep" })
+        );
+
+        let value = json!({ "_id": 1, "id": 2, "a__b": 3, "a_b": 4 });
+        assert_eq!(snake_case_json_keys(value.clone()), value);
+    }
+}
@@ -0,0 +1,151 @@
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+
+#[cfg(feature = "alloc")]
+use crate::{SnakeCase, SnakeCaseRef, SnakeStr};
+
+/// Is the given string a non-empty kebab-case string?
+/// In particular, does it match  ^[a-z][a-z0-9-]*$  ?
+pub const fn is_kebab_case(string: &str) -> bool {
+    let bytes = string.as_bytes();
+    if bytes.is_empty() || !bytes[0].is_ascii_lowercase() {
+        return false;
+    }
+    let mut i = 1;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if !(b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-') {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Only one possible error: the given string was not valid kebab-case.
+#[derive(Clone, Debug)]
+pub struct InvalidKebabCase;
+
+case_types! {
+    /// An owning string type that can only contain valid kebab-case.
+    /// In other words, it always matches  ^[a-z][a-z0-9-]*$
+    /// * Non-empty
+    /// * Starts with a lower case ASCII letter
+    /// * Contains only lower case ASCII letters, hyphens and digits
+    pub struct KebabCase;
+
+    /// An non-owning string type that can only refer to string containing valid kebab-case.
+    /// In other words, it always matches  ^[a-z][a-z0-9-]*$
+    /// * Non-empty
+    /// * Starts with a lower case ASCII letter
+    /// * Contains only lower case ASCII letters, hyphens and digits
+    pub struct KebabCaseRef;
+
+    error: InvalidKebabCase,
+    validator: is_kebab_case,
+    expected: "kebab-case",
+}
+
+#[cfg(feature = "alloc")]
+impl KebabCase {
+    /// `max-retry-count` -> `max_retry_count`
+    pub fn to_snake_case(&self) -> SnakeCase {
+        self.as_ref().to_snake_case()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl KebabCaseRef<'_> {
+    /// `max-retry-count` -> `max_retry_count`
+    pub fn to_snake_case(&self) -> SnakeCase {
+        // Every kebab-case string with the hyphens replaced is valid snake_case.
+        SnakeCase::from_string_unchecked(self.as_str().replace('-', "_"))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SnakeStr {
+    /// `max_retry_count` -> `max-retry-count`
+    ///
+    /// Fails if the string starts with an underscore.
+    pub fn to_kebab_case(&self) -> Result<KebabCase, InvalidKebabCase> {
+        KebabCase::try_from_string(self.as_str().replace('_', "-"))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<KebabCaseRef<'_>> for SnakeCase {
+    fn from(kebab: KebabCaseRef<'_>) -> Self {
+        kebab.to_snake_case()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&KebabCase> for SnakeCase {
+    fn from(kebab: &KebabCase) -> Self {
+        kebab.to_snake_case()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<KebabCase> for SnakeCase {
+    fn from(kebab: KebabCase) -> Self {
+        kebab.to_snake_case()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<SnakeCaseRef<'_>> for KebabCase {
+    type Error = InvalidKebabCase;
+
+    fn try_from(snake: SnakeCaseRef<'_>) -> Result<Self, Self::Error> {
+        snake.to_kebab_case()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&SnakeCase> for KebabCase {
+    type Error = InvalidKebabCase;
+
+    fn try_from(snake: &SnakeCase) -> Result<Self, Self::Error> {
+        snake.to_kebab_case()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<SnakeCase> for KebabCase {
+    type Error = InvalidKebabCase;
+
+    fn try_from(snake: SnakeCase) -> Result<Self, Self::Error> {
+        snake.to_kebab_case()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kebab_case() {
+        assert!(is_kebab_case("hello-world-42"));
+        assert!(is_kebab_case("a--b-"));
+        assert!(!is_kebab_case(""));
+        assert!(!is_kebab_case("-hello"));
+        assert!(!is_kebab_case("hello_world"));
+        assert!(!is_kebab_case("Hello-world"));
+        assert!(KebabCaseRef::try_from_str("42").is_err());
+    }
+
+    #[test]
+    fn kebab_snake_conversions() {
+        let snake = SnakeCase::try_from_str("dry_run__2").unwrap();
+        let kebab = snake.to_kebab_case().unwrap();
+        assert_eq!(kebab, "dry-run--2");
+        assert_eq!(kebab.to_snake_case(), snake);
+
+        assert!(SnakeCase::try_from_str("_private")
+            .unwrap()
+            .to_kebab_case()
+            .is_err());
+    }
+}
@@ -0,0 +1,72 @@
+//! Lenient serde support for [`SnakeCase`] that converts other case styles instead of failing.
+//!
+//! Use it with `#[serde(with = "snake_case::lenient")]`:
+//!
+//! ```
+//! # use snake_case::SnakeCase;
+//! #[derive(serde::Deserialize)]
+//! struct Event {
+//!     #[serde(with = "snake_case::lenient")]
+//!     kind: SnakeCase,
+//! }
+//!
+//! let event: Event = serde_json::from_str(r#"{ "kind": "userSignedUp" }"#).unwrap();
+//! assert_eq!(event.kind, "user_signed_up");
+//! ```
+//!
+//! Any string of ASCII letters, digits, underscores and hyphens is accepted
+//! (see [`crate::CaseStyle`]), and converted with [`SnakeCase::from_any_case`].
+//! Anything else, like spaces or non-ASCII characters, is still an error.
+//!
+//! Serializing is the same as for [`SnakeCase`].
+
+use alloc::{format, string::String};
+
+use serde::{de::Error as _, Deserialize as _, Deserializer, Serializer};
+
+use crate::{detect_case, CaseStyle, SnakeCase};
+
+pub fn serialize<S: Serializer>(snake: &SnakeCase, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(snake.as_str())
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SnakeCase, D::Error> {
+    let string = String::deserialize(deserializer)?;
+    match detect_case(&string) {
+        CaseStyle::Snake => return Ok(SnakeCase::from_string_unchecked(string)),
+        CaseStyle::Unknown => {}
+        _ => {
+            if let Ok(snake) = SnakeCase::from_any_case(&string) {
+                return Ok(snake);
+            }
+        }
+    }
+    Err(D::Error::custom(format!(
+        "Expected an identifier in any case style, got '{}'",
+        string
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, serde::Deserialize, serde::Serialize)]
+    struct Wrapper(#[serde(with = "super")] SnakeCase);
+
+    #[test]
+    fn lenient() {
+        let parse = |json: &str| serde_json::from_str::<Wrapper>(json).map(|w| w.0);
+        assert_eq!(parse(r#""hello_world""#).unwrap(), "hello_world");
+        assert_eq!(parse(r#""helloWorld""#).unwrap(), "hello_world");
+        assert_eq!(parse(r#""HTTPServer""#).unwrap(), "http_server");
+        assert_eq!(parse(r#""dry-run""#).unwrap(), "dry_run");
+        assert_eq!(parse(r#""MAX_SIZE""#).unwrap(), "max_size");
+        assert!(parse(r#""hello world""#).is_err());
+        assert!(parse(r#""""#).is_err());
+        assert!(parse(r#""--""#).is_err());
+
+        let wrapper = Wrapper(SnakeCase::try_from_str("a_b").unwrap());
+        assert_eq!(serde_json::to_string(&wrapper).unwrap(), r#""a_b""#);
+    }
+}
@@ -1,46 +1,521 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::manual_range_contains)]
 
-use std::{convert::TryFrom, fmt};
+#[cfg(any(feature = "alloc", test))]
+extern crate alloc;
+
+use core::{convert::TryFrom, fmt};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::Serialize;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+use serde::{Deserialize, Deserializer};
+
+#[macro_use]
+mod macros;
+
+#[cfg(feature = "alloc")]
+mod abbreviate;
+#[cfg(feature = "alloc")]
+mod acronyms;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+#[cfg(feature = "alloc")]
+mod arc_snake_case;
+#[cfg(feature = "alloc")]
+mod batch;
+#[cfg(feature = "borsh")]
+mod borsh_impl;
+#[cfg(feature = "alloc")]
+mod bounded;
+#[cfg(feature = "alloc")]
+mod builder;
+mod camel_case;
+mod case_style;
+#[cfg(feature = "clap")]
+mod clap_impl;
+mod convention;
+#[cfg(feature = "alloc")]
+mod convert;
+#[cfg(feature = "convert_case")]
+mod convert_case_impl;
+#[cfg(feature = "alloc")]
+mod convert_options;
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+#[cfg(feature = "diesel")]
+mod diesel_impl;
+mod display;
+#[cfg(feature = "std")]
+pub mod env;
+#[cfg(feature = "equivalent")]
+mod equivalent_impl;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "alloc")]
+mod group;
+#[cfg(feature = "heck")]
+mod heck_impl;
+#[cfg(feature = "inflect")]
+mod inflect;
+#[cfg(feature = "std")]
+mod interner;
+mod iter_ext;
+#[cfg(feature = "json")]
+mod json;
+mod kebab_case;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+pub mod lenient;
+#[cfg(feature = "diagnostics")]
+mod miette_impl;
+#[cfg(feature = "alloc")]
+mod mutation;
+mod names;
+mod natural_ord;
+#[cfg(feature = "alloc")]
+mod packed;
+mod pascal_case;
+#[cfg(feature = "std")]
+mod path_impl;
+#[cfg(feature = "alloc")]
+mod pattern;
+mod policy;
+#[cfg(feature = "alloc")]
+mod prefix_range;
+#[cfg(feature = "proc-macro")]
+mod proc_macro_impl;
+#[cfg(feature = "alloc")]
+mod prometheus;
+#[cfg(feature = "pyo3")]
+mod pyo3_impl;
+#[cfg(feature = "rand")]
+mod rand_impl;
+#[cfg(feature = "rayon")]
+mod rayon_impl;
+#[cfg(feature = "registry")]
+pub mod registry;
+#[cfg(feature = "alloc")]
+mod reserved_words;
+#[cfg(feature = "rkyv")]
+mod rkyv_impl;
+#[cfg(feature = "rusqlite")]
+mod rusqlite_impl;
+mod rust_ident;
+mod scan;
+#[cfg(feature = "schemars")]
+mod schemars_impl;
+mod screaming_snake_case;
+#[cfg(feature = "serde_with")]
+mod serde_with_impl;
+#[cfg(feature = "unicode")]
+mod slugify;
+mod snake_case_array;
+#[cfg(all(feature = "alloc", feature = "serde"))]
+mod snake_case_keys;
+mod snake_case_map;
+mod snake_case_path;
+#[cfg(feature = "alloc")]
+mod snake_case_set;
+mod snake_str;
+#[cfg(feature = "alloc")]
+mod snake_trie;
+#[cfg(feature = "alloc")]
+mod sql_ident;
+#[cfg(feature = "sqlx")]
+mod sqlx_impl;
+mod str_ext;
+#[cfg(feature = "alloc")]
+mod suggest;
+mod swar;
+#[cfg(feature = "unicode-idents")]
+mod unicode_snake_case;
+#[cfg(feature = "utoipa")]
+mod utoipa_impl;
+mod validator;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "alloc")]
+mod writer;
+
+pub use camel_case::{is_camel_case, CamelCaseRef, InvalidCamelCase};
+pub use case_style::{detect_case, CaseStyle};
+pub use convention::{CharSet, Convention, ConventionError, LetterCase, Validator};
+pub use iter_ext::{FilterSnakeCase, SnakeCaseIterExt};
+pub use kebab_case::{is_kebab_case, InvalidKebabCase, KebabCaseRef};
+pub use names::{FieldError, SnakeCaseFields, SnakeCaseTypeName, SnakeCaseVariants};
+pub use natural_ord::NaturalOrd;
+pub use pascal_case::{is_pascal_case, InvalidPascalCase, PascalCaseRef};
+#[cfg(feature = "std")]
+pub use path_impl::InvalidPathStem;
+pub use policy::{HasPolicy, Policy, PolicyViolation};
+pub use rust_ident::{InvalidRustIdent, RustIdent};
+pub use scan::{find_snake_case, take_snake_case_prefix};
+pub use screaming_snake_case::{
+    is_screaming_snake_case, InvalidScreamingSnakeCase, ScreamingSnakeCaseRef,
+};
+pub use snake_case_array::{SnakeCaseArray, SnakeCaseArrayError};
+pub use snake_case_map::SnakeCaseMap;
+pub use snake_case_path::{is_snake_case_path, InvalidSnakeCasePath, SnakeCasePathRef};
+pub use snake_str::SnakeStr;
+pub use str_ext::SnakeCaseStrExt;
+pub use swar::is_snake_case_fast;
+#[cfg(feature = "unicode-idents")]
+pub use unicode_snake_case::{is_unicode_snake_case, InvalidUnicodeSnakeCase, UnicodeSnakeCase};
+pub use validator::SnakeCaseValidator;
+
+#[cfg(feature = "derive")]
+pub use snake_case_macros::{
+    snake_case, snake_case_list, snake_case_map, SnakeCaseFields, SnakeCaseNewtype,
+    SnakeCaseTypeName, SnakeCaseVariants, ValidateSnakeCaseFields,
+};
+
+/// Used by the derive macros, so they work the same no matter which features the user's crate has.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use serde;
+}
+
+/// The serde impls for `#[derive(SnakeCaseNewtype)]`, if the `"serde"` feature is enabled.
+#[doc(hidden)]
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! __newtype_serde {
+    ($name:ident, $inner:ty) => {
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S: $crate::__private::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                $crate::__private::serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D: $crate::__private::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> ::core::result::Result<Self, D::Error> {
+                <$inner as $crate::__private::serde::Deserialize<'de>>::deserialize(deserializer)
+                    .map(Self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! __newtype_serde {
+    ($name:ident, $inner:ty) => {};
+}
+
+#[cfg(feature = "json")]
+pub use json::{snake_case_json_keys, validate_json_keys, KeyError};
+
+#[cfg(feature = "rayon")]
+pub use rayon_impl::validate_par;
+
+#[cfg(feature = "rkyv")]
+pub use rkyv_impl::ArchivedSnakeCase;
+
+#[cfg(feature = "std")]
+pub use interner::{SnakeCaseInterner, SnakeSymbol};
+
+#[cfg(feature = "clap")]
+pub use clap_impl::SnakeCaseValueParser;
+
+#[cfg(feature = "diagnostics")]
+pub use miette_impl::SnakeCaseDiagnostic;
+
+#[cfg(all(feature = "alloc", feature = "serde"))]
+pub use snake_case_keys::SnakeCaseKeys;
+
+#[cfg(feature = "serde_with")]
+pub use serde_with_impl::{AsSnakeCase, AsSnakeCaseLenient};
+
+#[cfg(feature = "alloc")]
+pub use {
+    acronyms::AcronymSet,
+    arc_snake_case::ArcSnakeCase,
+    batch::{validate_all, BatchError},
+    bounded::{BoundedSnakeCase, BoundedSnakeCaseError},
+    builder::SnakeCaseBuilder,
+    camel_case::CamelCase,
+    convention::Checked,
+    convert_options::{ConvertOptions, DigitBoundary},
+    group::group_by_prefix,
+    kebab_case::KebabCase,
+    mutation::{make_unique, EditableStorage},
+    packed::PackedSnakeCase,
+    pascal_case::PascalCase,
+    pattern::{InvalidSnakePattern, SnakePattern},
+    policy::PolicySnakeCase,
+    prefix_range::SnakeCasePrefix,
+    prometheus::{InvalidLabelKey, LabelKey, MetricName},
+    reserved_words::ReservedWords,
+    screaming_snake_case::ScreamingSnakeCase,
+    snake_case_path::SnakeCasePath,
+    snake_case_set::{SnakeCaseSet, SnakeCaseSetError},
+    snake_trie::{SnakeTrie, SnakeTrieIter},
+    sql_ident::SqlDialect,
+    suggest::suggest,
+    writer::SnakeCaseWriter,
+};
 
 // ----------------------------------------------------------------------------
 
 /// Is the given string a non-empty snake_case string?
 /// In particular, does it match  ^[_a-z][_a-z0-9]*$  ?
 pub const fn is_snake_case(string: &str) -> bool {
+    matches!(validate(string), Ok(()))
+}
+
+/// Like [`is_snake_case`], but tells you what is wrong, and where.
+///
+/// Unlike [`InvalidSnakeCase`], the error can be matched on in const contexts,
+/// to turn it into a meaningful compile error (as `snake_case_lit!` does):
+///
+/// ```
+/// # use snake_case::{check, ErrorKind};
+/// const PREFIX: &str = "app_";
+/// const _: () = match check(PREFIX) {
+///     Ok(()) => {}
+///     Err((ErrorKind::UppercaseChar, _)) => panic!("PREFIX must be lower case"),
+///     Err(_) => panic!("PREFIX must be snake_case"),
+/// };
+///
+/// assert_eq!(check("appName"), Err((ErrorKind::UppercaseChar, 3)));
+/// ```
+pub const fn check(string: &str) -> Result<(), (ErrorKind, usize)> {
+    match validate(string) {
+        Ok(()) => Ok(()),
+        Err(err) => Err((err.kind(), err.offset())),
+    }
+}
+
+/// Like [`is_snake_case`], but for bytes that may not be UTF-8, like a network buffer.
+///
+/// Valid snake_case is ASCII, so bytes that pass this are also valid UTF-8.
+///
+/// ```
+/// # use snake_case::is_snake_case_bytes;
+/// assert!(is_snake_case_bytes(b"hello_world"));
+/// assert!(!is_snake_case_bytes(b"hello\xFFworld"));
+/// ```
+pub const fn is_snake_case_bytes(bytes: &[u8]) -> bool {
+    matches!(validate_bytes(bytes), Ok(()))
+}
+
+/// Like [`is_snake_case`], but tells you what is wrong.
+pub(crate) const fn validate(string: &str) -> Result<(), InvalidSnakeCase> {
+    validate_bytes(string.as_bytes())
+}
+
+/// Like [`validate`], but for bytes that may not be UTF-8.
+///
+/// An invalid UTF-8 sequence is reported as an [`InvalidSnakeCase::InvalidChar`] (or `InvalidStartChar`)
+/// of [`char::REPLACEMENT_CHARACTER`].
+pub(crate) const fn validate_bytes(bytes: &[u8]) -> Result<(), InvalidSnakeCase> {
+    if bytes.is_empty() {
+        return Err(InvalidSnakeCase::Empty);
+    }
+    match find_invalid_byte(bytes, true) {
+        Some(i) => Err(invalid_char(bytes, i, i)),
+        None => Ok(()),
+    }
+}
+
+/// The index of the first byte that can't be in snake_case, if any.
+///
+/// `at_start` is whether `bytes` starts the string, where digits are not allowed.
+/// Used by everything that validates snake_case piece by piece, so they all agree.
+pub(crate) const fn find_invalid_byte(bytes: &[u8], at_start: bool) -> Option<usize> {
     // we only care about ascii chars, which fit in a byte.
     // iterating over utf8 continuation bytes and the like will not count as valid snake case anyway.
-    let (len, bytes) = (string.len(), string.as_bytes());
-    const fn valid_start(b: u8) -> bool {
-        b == b'_' || b'a' <= b && b <= b'z'
-    }
     const fn is_snake_case_character(c: u8) -> bool {
         b'a' <= c && c <= b'z' || b'0' <= c && c <= b'9' || c == b'_'
     }
-    // non-empty and starts with a..z or _
-    if bytes.is_empty() || !valid_start(bytes[0]) {
-        return false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let is_start = at_start && i == 0;
+        if !is_snake_case_character(b) || is_start && b.is_ascii_digit() {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// The error for the invalid character starting at `bytes[index]`, which is at `offset` in the whole string.
+///
+/// We only ever call this at the first invalid byte, and everything before it is ASCII,
+/// so `index` is always on a char boundary (if the bytes are UTF-8 at all).
+pub(crate) const fn invalid_char(bytes: &[u8], index: usize, offset: usize) -> InvalidSnakeCase {
+    if bytes[index].is_ascii_uppercase() {
+        return InvalidSnakeCase::UppercaseChar { offset };
+    }
+    let char = char_at(bytes, index);
+    if offset == 0 {
+        InvalidSnakeCase::InvalidStartChar { char, offset }
+    } else {
+        InvalidSnakeCase::InvalidChar { char, offset }
+    }
+}
+
+/// Decode the UTF-8 character starting at `bytes[index]`.
+///
+/// Gives [`char::REPLACEMENT_CHARACTER`] if it is not valid UTF-8, including if it is cut off by the end of `bytes`.
+pub(crate) const fn char_at(bytes: &[u8], index: usize) -> char {
+    let first = bytes[index] as u32;
+    let (len, mut code) = if first < 0x80 {
+        (1, first)
+    } else if first < 0xC0 {
+        (0, 0) // a continuation byte: not UTF-8
+    } else if first < 0xE0 {
+        (2, first & 0x1F)
+    } else if first < 0xF0 {
+        (3, first & 0x0F)
+    } else {
+        (4, first & 0x07)
+    };
+    let mut utf8 = len != 0 && index + len <= bytes.len();
+    let mut i = 1;
+    while utf8 && i < len {
+        let b = bytes[index + i];
+        utf8 = b & 0xC0 == 0x80;
+        code = (code << 6) | (b as u32 & 0x3F);
+        i += 1;
+    }
+    match char::from_u32(code) {
+        Some(c) if utf8 => c,
+        _ => char::REPLACEMENT_CHARACTER,
     }
-    //check the rest
-    let mut i = 1; // we already checked the first byte, its fine
-    loop {
-        if i >= len - 1 {
-            break true;
+}
+
+/// Check a single word to be joined into snake_case: lower case ASCII letters and digits only.
+///
+/// Error offsets are relative to `start`, where the word will end up in the joined string.
+pub(crate) fn validate_word(word: &str, start: usize) -> Result<(), InvalidSnakeCase> {
+    if word.is_empty() {
+        return Err(InvalidSnakeCase::Empty);
+    }
+    for (i, c) in word.char_indices() {
+        let offset = start + i;
+        if c.is_ascii_uppercase() {
+            return Err(InvalidSnakeCase::UppercaseChar { offset });
         }
-        if !is_snake_case_character(bytes[i]) {
-            break false;
+        if !(c.is_ascii_lowercase() || c.is_ascii_digit()) {
+            return Err(if offset == 0 {
+                InvalidSnakeCase::InvalidStartChar { char: c, offset }
+            } else {
+                InvalidSnakeCase::InvalidChar { char: c, offset }
+            });
         }
-        i += 1;
     }
+    Ok(())
 }
 
 // ----------------------------------------------------------------------------
 
-/// Only one possible error: the given string was not valid snake_case.
-#[derive(Clone, Debug)]
-pub struct InvalidSnakeCase;
+/// The reason a string is not valid snake_case.
+///
+/// All offsets are byte offsets into the string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidSnakeCase {
+    /// The string was empty.
+    Empty,
+
+    /// The first character was neither a lower case ASCII letter nor an underscore (e.g. a digit).
+    InvalidStartChar { char: char, offset: usize },
+
+    /// A character that is not a lower case ASCII letter, digit or underscore.
+    InvalidChar { char: char, offset: usize },
+
+    /// An upper case ASCII letter.
+    UppercaseChar { offset: usize },
+}
+
+impl InvalidSnakeCase {
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Empty => ErrorKind::Empty,
+            Self::InvalidStartChar { .. } => ErrorKind::InvalidStartChar,
+            Self::InvalidChar { .. } => ErrorKind::InvalidChar,
+            Self::UppercaseChar { .. } => ErrorKind::UppercaseChar,
+        }
+    }
+
+    /// Where the problem is. Zero for [`Self::Empty`].
+    pub const fn offset(&self) -> usize {
+        match self {
+            Self::Empty => 0,
+            Self::InvalidStartChar { offset, .. }
+            | Self::InvalidChar { offset, .. }
+            | Self::UppercaseChar { offset } => *offset,
+        }
+    }
+}
+
+/// The variant of an [`InvalidSnakeCase`], without the details, as returned by [`check`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    Empty,
+    InvalidStartChar,
+    InvalidChar,
+    UppercaseChar,
+}
+
+impl ErrorKind {
+    /// A short description, like `contains an upper case letter`.
+    ///
+    /// This works in const contexts too, as `panic!("{}", kind.message())`.
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::Empty => "empty string",
+            Self::InvalidStartChar => "must start with a lower case letter or underscore",
+            Self::InvalidChar => "may only contain lower case letters, digits and underscores",
+            Self::UppercaseChar => "contains an upper case letter",
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl fmt::Display for InvalidSnakeCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "expected snake_case, found an empty string"),
+            Self::InvalidStartChar { char, offset } => write!(
+                f,
+                "snake_case must start with a lower case letter or underscore, found {:?} at offset {}",
+                char, offset
+            ),
+            Self::InvalidChar { char, offset } => write!(
+                f,
+                "invalid character {:?} at offset {}: snake_case may only contain lower case letters, digits and underscores",
+                char, offset
+            ),
+            Self::UppercaseChar { offset } => write!(
+                f,
+                "upper case character at offset {}: snake_case must be lower case",
+                offset
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidSnakeCase {}
 
 // ----------------------------------------------------------------------------
 
@@ -49,36 +524,232 @@ pub struct InvalidSnakeCase;
 /// * Non-empty
 /// * Starts with a lower case ASCII letter or underscore
 /// * Contains only lower case ASCII letters, underscores and digits
-#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct SnakeCase(String);
+///
+/// It is rarely edited after construction, so by default it is stored as a `Box<str>`:
+/// two words instead of the three of a `String`, and no spare capacity.
+/// With the `smallstr` feature, strings of up to 24 bytes are instead stored inline, without allocating.
+///
+/// You can also pick the storage yourself, e.g. `SnakeCase<Arc<str>>` (a.k.a. [`ArcSnakeCase`]) or `SnakeCase<&'static str>`.
+/// Anything that is `AsRef<str>` gets the same validation, comparisons and `as_str`,
+/// and the storages listed under [`SnakeCaseStorage`] also deref to [`SnakeStr`]:
+///
+/// ```
+/// # use snake_case::SnakeCase;
+/// use std::sync::Arc;
+///
+/// let shared: SnakeCase<Arc<str>> = SnakeCase::try_new(Arc::from("query_plan")).unwrap();
+/// assert_eq!(shared, SnakeCase::try_from_str("query_plan").unwrap());
+/// assert_eq!(shared.word_count(), 2);
+/// assert!(SnakeCase::try_new("QueryPlan").is_err());
+/// ```
+///
+/// The conversions from (and into) `String`, and the integrations with other crates,
+/// are only implemented for the default storage.
+///
+/// Note that `smallstr` changes what the default storage *is*, so it is not purely additive:
+/// code that spells out the default's storage, like `let x: SnakeCase = SnakeCase::try_new(Box::<str>::from("a"))?`,
+/// stops compiling when some other crate in the build turns it on.
+/// Use `try_from_str` and friends, or name the storage (`SnakeCase<Box<str>>`), to be safe either way.
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+pub struct SnakeCase<S = Repr>(S);
+
+#[cfg(all(feature = "alloc", not(feature = "smallstr")))]
+type Repr = alloc::boxed::Box<str>;
 
+#[cfg(feature = "smallstr")]
+type Repr = smallstr::SmallString<[u8; INLINE_CAPACITY]>;
+
+#[cfg(feature = "smallstr")]
+const INLINE_CAPACITY: usize = 24;
+
+/// The storages a [`SnakeCase`] derefs to [`SnakeStr`] with.
+///
+/// [`SnakeCase::try_new`] validates whatever `as_ref` returns once,
+/// so this is limited to storages known to return the same string every time.
+/// Other storages still get `as_str` and the comparisons, just not the [`SnakeStr`] methods.
+#[cfg(feature = "alloc")]
+pub trait SnakeCaseStorage: AsRef<str> + storage::Sealed {}
+
+#[cfg(feature = "alloc")]
+mod storage {
+    use alloc::{borrow::Cow, boxed::Box, rc::Rc, string::String, sync::Arc};
+
+    pub trait Sealed {}
+
+    macro_rules! storages {
+        ($($ty:ty),*) => {
+            $(
+                impl Sealed for $ty {}
+                impl super::SnakeCaseStorage for $ty {}
+            )*
+        };
+    }
+
+    storages!(Box<str>, String, Arc<str>, Rc<str>, &str, Cow<'_, str>);
+
+    #[cfg(feature = "smallstr")]
+    storages!(super::Repr);
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> SnakeCase<S> {
+    /// Validate any string storage.
+    pub fn try_new(storage: S) -> Result<SnakeCase<S>, InvalidSnakeCase> {
+        swar::validate_fast(storage.as_ref())?;
+        Ok(SnakeCase(storage))
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref()
+    }
+
+    pub fn as_ref(&self) -> SnakeCaseRef<'_> {
+        SnakeCaseRef(self.0.as_ref())
+    }
+
+    /// Returns the storage.
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl SnakeCase {
+    /// Strings up to this long are stored inline, without allocating.
+    ///
+    /// Being a multiple of the word size, this costs no more than a shorter buffer would.
+    #[cfg(feature = "smallstr")]
+    pub const INLINE_CAPACITY: usize = INLINE_CAPACITY;
+
     pub fn try_from_str(s: &str) -> Result<SnakeCase, InvalidSnakeCase> {
-        if is_snake_case(s) {
-            Ok(SnakeCase(s.to_string()))
-        } else {
-            Err(InvalidSnakeCase)
-        }
+        swar::validate_fast(s)?;
+        Ok(SnakeCase(s.into()))
     }
 
+    /// Takes ownership of the string, dropping any spare capacity.
     pub fn try_from_string(s: String) -> Result<SnakeCase, InvalidSnakeCase> {
-        if is_snake_case(&s) {
-            Ok(SnakeCase(s))
+        swar::validate_fast(&s)?;
+        Ok(SnakeCase::from_string_unchecked(s))
+    }
+
+    /// The caller must make sure the string is valid snake_case.
+    pub(crate) fn from_string_unchecked(string: String) -> SnakeCase {
+        #[cfg(not(feature = "smallstr"))]
+        {
+            SnakeCase(string.into_boxed_str())
+        }
+        #[cfg(feature = "smallstr")]
+        {
+            if string.len() <= SnakeCase::INLINE_CAPACITY {
+                SnakeCase(Repr::from(string.as_str()))
+            } else {
+                SnakeCase(Repr::from(string.into_boxed_str()))
+            }
+        }
+    }
+
+    /// Lossy conversion from any case style (`HelloWorld`, `hello-world`, `HELLO WORLD`, …).
+    ///
+    /// The input is split into words on non-alphanumeric characters and case changes,
+    /// and the lower cased words are joined with underscores.
+    /// If the result would start with a digit, it is prefixed with an underscore.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCase;
+    /// assert_eq!(SnakeCase::from_any_case("HelloWorld").unwrap(), "hello_world");
+    /// assert_eq!(SnakeCase::from_any_case("HTTPServer").unwrap(), "http_server");
+    /// assert_eq!(SnakeCase::from_any_case("HELLO WORLD").unwrap(), "hello_world");
+    /// ```
+    ///
+    /// Fails with [`InvalidSnakeCase::Empty`] if the input contains no ASCII letters or digits.
+    pub fn from_any_case(s: &str) -> Result<SnakeCase, InvalidSnakeCase> {
+        convert::to_snake_case_string(s)
+            .map(SnakeCase::from_string_unchecked)
+            .ok_or(InvalidSnakeCase::Empty)
+    }
+
+    /// Join the parts with underscores.
+    ///
+    /// This never needs to re-validate anything, since valid snake_case joined with underscores is valid snake_case.
+    ///
+    /// ```
+    /// # use snake_case::{SnakeCase, SnakeCaseRef};
+    /// let app = SnakeCaseRef::try_from_str("app").unwrap();
+    /// let key = SnakeCase::try_from_str("max_depth").unwrap();
+    /// assert_eq!(SnakeCase::join([app, key.as_ref()]).unwrap(), "app_max_depth");
+    /// ```
+    ///
+    /// Fails with [`InvalidSnakeCase::Empty`] if there are no parts.
+    pub fn join<I>(parts: I) -> Result<SnakeCase, InvalidSnakeCase>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<SnakeStr>,
+    {
+        let mut string = String::new();
+        for part in parts {
+            if !string.is_empty() {
+                string.push('_');
+            }
+            string.push_str(part.as_ref().as_str());
+        }
+        if string.is_empty() {
+            Err(InvalidSnakeCase::Empty)
         } else {
-            Err(InvalidSnakeCase)
+            Ok(SnakeCase::from_string_unchecked(string))
         }
     }
 
-    pub fn as_str(&self) -> &str {
-        &self.0
+    /// Returns the contents as a `String`, without copying
+    /// (unless the string was stored inline with the `smallstr` feature).
+    pub fn into_string(self) -> String {
+        self.0.into_string()
+    }
+
+    /// Returns the contents as a `Box<str>`, without copying.
+    pub fn into_boxed_str(self) -> alloc::boxed::Box<str> {
+        self.into_string().into_boxed_str()
+    }
+
+    /// The UTF-8 (in fact ASCII) bytes, without copying.
+    pub fn into_bytes(self) -> alloc::vec::Vec<u8> {
+        self.into_string().into_bytes()
+    }
+
+    /// Leak the string, so it lives for the rest of the process.
+    ///
+    /// For names that are created once at startup and then used everywhere:
+    ///
+    /// ```
+    /// # use snake_case::{SnakeCase, SnakeCaseRef};
+    /// let name: SnakeCaseRef<'static> = SnakeCase::from_any_case("EventName").unwrap().leak();
+    /// assert_eq!(name, "event_name");
+    /// ```
+    pub fn leak(self) -> SnakeCaseRef<'static> {
+        SnakeCaseRef(alloc::boxed::Box::leak(self.into_boxed_str()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<SnakeCase> for String {
+    fn from(snake: SnakeCase) -> Self {
+        snake.into_string()
     }
+}
 
-    pub fn as_ref(&self) -> SnakeCaseRef {
-        SnakeCaseRef(&self.0)
+#[cfg(feature = "alloc")]
+impl From<SnakeCase> for alloc::boxed::Box<str> {
+    fn from(snake: SnakeCase) -> Self {
+        snake.into_boxed_str()
     }
 }
 
+#[cfg(feature = "alloc")]
 impl TryFrom<&str> for SnakeCase {
     type Error = InvalidSnakeCase;
 
@@ -87,6 +758,7 @@ impl TryFrom<&str> for SnakeCase {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl TryFrom<String> for SnakeCase {
     type Error = InvalidSnakeCase;
 
@@ -95,56 +767,181 @@ impl TryFrom<String> for SnakeCase {
     }
 }
 
-impl std::borrow::Borrow<str> for SnakeCase {
+#[cfg(feature = "alloc")]
+impl TryFrom<&String> for SnakeCase {
+    type Error = InvalidSnakeCase;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        SnakeCase::try_from_str(s)
+    }
+}
+
+/// Reuses the allocation.
+#[cfg(feature = "alloc")]
+impl TryFrom<alloc::boxed::Box<str>> for SnakeCase {
+    type Error = InvalidSnakeCase;
+
+    fn try_from(s: alloc::boxed::Box<str>) -> Result<Self, Self::Error> {
+        SnakeCase::try_from_string(s.into())
+    }
+}
+
+/// Reuses the allocation if the `Cow` is owned.
+#[cfg(feature = "alloc")]
+impl TryFrom<alloc::borrow::Cow<'_, str>> for SnakeCase {
+    type Error = InvalidSnakeCase;
+
+    fn try_from(s: alloc::borrow::Cow<'_, str>) -> Result<Self, Self::Error> {
+        match s {
+            alloc::borrow::Cow::Borrowed(s) => SnakeCase::try_from_str(s),
+            alloc::borrow::Cow::Owned(s) => SnakeCase::try_from_string(s),
+        }
+    }
+}
+
+/// A single lower case ASCII letter or underscore.
+#[cfg(feature = "alloc")]
+impl TryFrom<char> for SnakeCase {
+    type Error = InvalidSnakeCase;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let mut buf = [0; 4];
+        SnakeCase::try_from_str(c.encode_utf8(&mut buf))
+    }
+}
+
+/// Collect into a `Result<SnakeCase, _>`, see [`SnakeCase::join`].
+///
+/// ```
+/// # use snake_case::{SnakeCase, SnakeCaseRef};
+/// let parts = ["app", "db", "timeout"].map(|s| SnakeCaseRef::try_from_str(s).unwrap());
+/// let joined: Result<SnakeCase, _> = parts.iter().copied().collect();
+/// assert_eq!(joined.unwrap(), "app_db_timeout");
+/// ```
+#[cfg(feature = "alloc")]
+impl<'a> core::iter::FromIterator<SnakeCaseRef<'a>> for Result<SnakeCase, InvalidSnakeCase> {
+    fn from_iter<I: IntoIterator<Item = SnakeCaseRef<'a>>>(parts: I) -> Self {
+        SnakeCase::join(parts)
+    }
+}
+
+/// ```
+/// # use snake_case::SnakeCase;
+/// let id: SnakeCase = "hello_world".parse().unwrap();
+/// assert_eq!(id, "hello_world");
+/// assert!("HelloWorld".parse::<SnakeCase>().is_err());
+/// ```
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for SnakeCase {
+    type Err = InvalidSnakeCase;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SnakeCase::try_from_str(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::borrow::Borrow<str> for SnakeCase<S> {
     fn borrow(&self) -> &str {
-        &self.0
+        self.as_str()
     }
 }
 
-impl fmt::Debug for SnakeCase {
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> fmt::Debug for SnakeCase<S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.as_str().fmt(f)
     }
 }
 
-impl fmt::Display for SnakeCase {
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> fmt::Display for SnakeCase<S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.as_str().fmt(f)
     }
 }
 
-#[cfg(feature = "serde")]
-impl<'de> Deserialize<'de> for SnakeCase {
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+#[cfg(all(feature = "alloc", feature = "serde"))]
+impl<S: AsRef<str>> Serialize for SnakeCase<S> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "serde"))]
+impl<'de, S: AsRef<str> + From<String>> Deserialize<'de> for SnakeCase<S> {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
         let string = String::deserialize(deserializer)?;
-        SnakeCase::try_from_str(&string).map_err(|_: InvalidSnakeCase| {
-            serde::de::Error::custom(format!("Expected snake_case, got '{}'", string))
-        })
+        match validate(&string) {
+            Ok(()) => Ok(SnakeCase(S::from(string))),
+            Err(err) => Err(serde::de::Error::custom(alloc::format!(
+                "Expected snake_case, got '{}': {}",
+                string,
+                err
+            ))),
+        }
+    }
+}
+
+// Comparisons only look at the string, whatever the storage:
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>, T: AsRef<str>> core::cmp::PartialEq<SnakeCase<T>> for SnakeCase<S> {
+    fn eq(&self, other: &SnakeCase<T>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> Eq for SnakeCase<S> {}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>, T: AsRef<str>> core::cmp::PartialOrd<SnakeCase<T>> for SnakeCase<S> {
+    fn partial_cmp(&self, other: &SnakeCase<T>) -> Option<core::cmp::Ordering> {
+        self.as_str().partial_cmp(other.as_str())
     }
 }
 
-impl std::cmp::PartialEq<SnakeCase> for &str {
-    fn eq(&self, other: &SnakeCase) -> bool {
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> Ord for SnakeCase<S> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::hash::Hash for SnakeCase<S> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::cmp::PartialEq<SnakeCase<S>> for &str {
+    fn eq(&self, other: &SnakeCase<S>) -> bool {
         *self == other.as_str()
     }
 }
 
-impl std::cmp::PartialEq<str> for SnakeCase {
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::cmp::PartialEq<str> for SnakeCase<S> {
     fn eq(&self, other: &str) -> bool {
         self.as_str() == other
     }
 }
 
-impl std::cmp::PartialEq<&str> for SnakeCase {
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::cmp::PartialEq<&str> for SnakeCase<S> {
     fn eq(&self, other: &&str) -> bool {
         self.as_str() == *other
     }
 }
 
-impl std::cmp::PartialEq<String> for SnakeCase {
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::cmp::PartialEq<String> for SnakeCase<S> {
     fn eq(&self, other: &String) -> bool {
         self.as_str() == *other
     }
@@ -162,26 +959,90 @@ impl std::cmp::PartialEq<String> for SnakeCase {
 pub struct SnakeCaseRef<'a>(&'a str);
 
 impl<'a> SnakeCaseRef<'a> {
-    pub const fn try_from_str(s: &str) -> Result<SnakeCaseRef, InvalidSnakeCase> {
-        if is_snake_case(s) {
-            Ok(SnakeCaseRef(s))
-        } else {
-            Err(InvalidSnakeCase)
+    pub const fn try_from_str(s: &str) -> Result<SnakeCaseRef<'_>, InvalidSnakeCase> {
+        match validate(s) {
+            Ok(()) => Ok(SnakeCaseRef(s)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Validate bytes that may not be UTF-8, without converting them to a `str` first.
+    ///
+    /// ```
+    /// # use snake_case::{InvalidSnakeCase, SnakeCaseRef};
+    /// assert_eq!(SnakeCaseRef::try_from_bytes(b"max_len").unwrap(), "max_len");
+    /// assert_eq!(
+    ///     SnakeCaseRef::try_from_bytes(b"max\xFF"),
+    ///     Err(InvalidSnakeCase::InvalidChar { char: char::REPLACEMENT_CHARACTER, offset: 3 })
+    /// );
+    /// ```
+    pub const fn try_from_bytes(bytes: &[u8]) -> Result<SnakeCaseRef<'_>, InvalidSnakeCase> {
+        match validate_bytes(bytes) {
+            // SAFETY: valid snake_case is ASCII, so also valid UTF-8.
+            Ok(()) => Ok(SnakeCaseRef(unsafe {
+                core::str::from_utf8_unchecked(bytes)
+            })),
+            Err(err) => Err(err),
         }
     }
 
+    /// Like [`Self::try_from_str`], but panics on invalid input.
+    ///
+    /// In a `const` context this means a compile error, so this is a safe way to create constants:
+    ///
+    /// ```
+    /// # use snake_case::SnakeCaseRef;
+    /// const ID: SnakeCaseRef<'static> = SnakeCaseRef::from_str_or_panic("my_little_snake");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use snake_case::SnakeCaseRef;
+    /// const ID: SnakeCaseRef<'static> = SnakeCaseRef::from_str_or_panic("Python");
+    /// ```
+    pub const fn from_str_or_panic(s: &str) -> SnakeCaseRef<'_> {
+        match validate(s) {
+            Ok(()) => SnakeCaseRef(s),
+            Err(InvalidSnakeCase::Empty) => panic!("invalid snake_case: empty string"),
+            Err(InvalidSnakeCase::InvalidStartChar { .. }) => {
+                panic!("invalid snake_case: must start with a lower case letter or underscore")
+            }
+            Err(InvalidSnakeCase::InvalidChar { .. }) => panic!(
+                "invalid snake_case: may only contain lower case letters, digits and underscores"
+            ),
+            Err(InvalidSnakeCase::UppercaseChar { .. }) => {
+                panic!("invalid snake_case: contains an upper case letter")
+            }
+        }
+    }
+
+    /// A `SnakeCaseRef<'static>` from a string literal or other `&'static str`,
+    /// panicking if it's not snake_case, like [`Self::from_str_or_panic`].
+    ///
+    /// ```
+    /// # use snake_case::SnakeCaseRef;
+    /// static EVENTS: [SnakeCaseRef<'static>; 2] =
+    ///     [SnakeCaseRef::from_static("app_start"), SnakeCaseRef::from_static("app_stop")];
+    /// ```
+    pub const fn from_static(s: &'static str) -> SnakeCaseRef<'static> {
+        SnakeCaseRef::from_str_or_panic(s)
+    }
+
     pub const fn as_str(&self) -> &'a str {
         self.0
     }
 
+    #[cfg(feature = "alloc")]
     pub fn to_owned(&self) -> SnakeCase {
-        SnakeCase(self.0.to_string())
+        SnakeCase(self.0.into())
     }
 }
 
 #[cfg(feature = "const_literals")]
 /// an unsafe constructor for SnakeCaseRef. caller has to make sure the input is in fact valid.
-pub const unsafe fn from_str_unchecked(s: &str) -> SnakeCaseRef {
+///
+/// # Safety
+/// `s` must be valid snake_case, i.e. `is_snake_case(s)` must return `true`.
+pub const unsafe fn from_str_unchecked(s: &str) -> SnakeCaseRef<'_> {
     SnakeCaseRef(s)
 }
 #[cfg(feature = "const_literals")]
@@ -190,17 +1051,49 @@ pub const unsafe fn from_str_unchecked(s: &str) -> SnakeCaseRef {
 /// ```
 /// use snake_case::snake_case_lit;
 /// let snake_case = snake_case_lit!("my_little_snake");
-/// // let bad_snake =  snake_case_lit!("Python"); <- this wont compile
+/// ```
+///
+/// Any `&'static str` constant expression works, e.g. `concat!` or a `const` item:
+///
+/// ```
+/// use snake_case::snake_case_lit;
+/// const PREFIX: &str = "my";
+/// let composed = snake_case_lit!(concat!("my", "_little_snake"));
+/// let from_const = snake_case_lit!(PREFIX);
+/// ```
+///
+/// Invalid input is a compile error that names the input and what is wrong with it, like
+/// ``invalid snake_case: "Python" contains an upper case letter``:
+///
+/// ```compile_fail
+/// use snake_case::snake_case_lit;
+/// let bad_snake = snake_case_lit!("Python"); // <- this wont compile
 /// ```
 #[macro_export]
 macro_rules! snake_case_lit {
     ($s:expr) => {{
-        struct Valid<const B: bool>;
-        let _valid: Valid<true> = Valid::<{ snake_case::is_snake_case($s) }>;
-        unsafe {
-            // this is perfectly safe, wouldnt even compile otherwise.
-            snake_case::from_str_unchecked($s)
-        }
+        const SNAKE_CASE: $crate::SnakeCaseRef<'static> = match $crate::check($s) {
+            Ok(()) => $crate::SnakeCaseRef::from_str_or_panic($s),
+            Err(($crate::ErrorKind::Empty, _)) => {
+                panic!(concat!("invalid snake_case: ", stringify!($s), " is empty"))
+            }
+            Err(($crate::ErrorKind::InvalidStartChar, _)) => panic!(concat!(
+                "invalid snake_case: ",
+                stringify!($s),
+                " must start with a lower case letter or underscore"
+            )),
+            Err(($crate::ErrorKind::UppercaseChar, _)) => panic!(concat!(
+                "invalid snake_case: ",
+                stringify!($s),
+                " contains an upper case letter"
+            )),
+            Err(_) => panic!(concat!(
+                "invalid snake_case: ",
+                stringify!($s),
+                " may only contain lower case letters, digits and underscores"
+            )),
+        };
+        SNAKE_CASE
     }};
 }
 
@@ -212,9 +1105,27 @@ impl<'a> TryFrom<&'a str> for SnakeCaseRef<'a> {
     }
 }
 
-impl std::borrow::Borrow<str> for SnakeCaseRef<'_> {
+impl core::borrow::Borrow<str> for SnakeCaseRef<'_> {
     fn borrow(&self) -> &str {
-        &self.0
+        self.0
+    }
+}
+
+/// Borrows the string from the deserializer, so this only works for formats (like JSON)
+/// where the string can be borrowed from the input, with no escape sequences.
+#[cfg(feature = "serde")]
+impl<'de: 'a, 'a> serde::Deserialize<'de> for SnakeCaseRef<'a> {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let string = <&'de str>::deserialize(deserializer)?;
+        SnakeCaseRef::try_from_str(string).map_err(|err: InvalidSnakeCase| {
+            serde::de::Error::custom(format_args!(
+                "Expected snake_case, got '{}': {}",
+                string, err
+            ))
+        })
     }
 }
 
@@ -230,40 +1141,94 @@ impl fmt::Display for SnakeCaseRef<'_> {
     }
 }
 
-impl std::cmp::PartialEq<SnakeCaseRef<'_>> for str {
+impl core::cmp::PartialEq<SnakeCaseRef<'_>> for str {
     fn eq(&self, other: &SnakeCaseRef<'_>) -> bool {
         self == other.0
     }
 }
 
-impl std::cmp::PartialEq<SnakeCaseRef<'_>> for &str {
+impl core::cmp::PartialEq<SnakeCaseRef<'_>> for &str {
     fn eq(&self, other: &SnakeCaseRef<'_>) -> bool {
         *self == other.0
     }
 }
 
-impl std::cmp::PartialEq<str> for SnakeCaseRef<'_> {
+impl core::cmp::PartialEq<str> for SnakeCaseRef<'_> {
     fn eq(&self, other: &str) -> bool {
         self.as_str() == other
     }
 }
 
-impl std::cmp::PartialEq<&str> for SnakeCaseRef<'_> {
+impl core::cmp::PartialEq<&str> for SnakeCaseRef<'_> {
     fn eq(&self, other: &&str) -> bool {
         self.as_str() == *other
     }
 }
 
-impl std::cmp::PartialEq<String> for SnakeCaseRef<'_> {
+#[cfg(feature = "alloc")]
+impl core::cmp::PartialEq<String> for SnakeCaseRef<'_> {
     fn eq(&self, other: &String) -> bool {
         self.as_str() == *other
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::cmp::PartialEq<SnakeCase<S>> for str {
+    fn eq(&self, other: &SnakeCase<S>) -> bool {
+        self == other.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::cmp::PartialEq<SnakeCase<S>> for String {
+    fn eq(&self, other: &SnakeCase<S>) -> bool {
+        self == other.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::cmp::PartialEq<SnakeCaseRef<'_>> for String {
+    fn eq(&self, other: &SnakeCaseRef<'_>) -> bool {
+        self == other.as_str()
+    }
+}
+
+// The owned and borrowed types compare like the strings they contain:
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::cmp::PartialEq<SnakeCaseRef<'_>> for SnakeCase<S> {
+    fn eq(&self, other: &SnakeCaseRef<'_>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::cmp::PartialEq<SnakeCase<S>> for SnakeCaseRef<'_> {
+    fn eq(&self, other: &SnakeCase<S>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::cmp::PartialOrd<SnakeCaseRef<'_>> for SnakeCase<S> {
+    fn partial_cmp(&self, other: &SnakeCaseRef<'_>) -> Option<core::cmp::Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::cmp::PartialOrd<SnakeCase<S>> for SnakeCaseRef<'_> {
+    fn partial_cmp(&self, other: &SnakeCase<S>) -> Option<core::cmp::Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 #[cfg(test)]
 mod tests {
+    use alloc::{string::ToString as _, vec, vec::Vec};
+
     use super::*;
 
     #[test]
@@ -277,6 +1242,66 @@ mod tests {
         assert!(SnakeCase::try_from_str("").is_err());
         assert!(SnakeCase::try_from_str("42").is_err());
         assert!(SnakeCase::try_from_str("_").is_ok());
+        assert!(SnakeCase::try_from_str("hello_World").is_err());
+        assert!(SnakeCase::try_from_str("hello!").is_err());
+    }
+
+    #[test]
+    fn try_from_stringish() {
+        use alloc::{borrow::Cow, boxed::Box};
+
+        let boxed: Box<str> = "a_b".into();
+        assert_eq!(SnakeCase::try_from(boxed).unwrap(), "a_b");
+        assert_eq!(SnakeCase::try_from(&String::from("a_b")).unwrap(), "a_b");
+        assert_eq!(SnakeCase::try_from(Cow::Borrowed("a")).unwrap(), "a");
+        assert!(SnakeCase::try_from(Cow::Owned(String::from("A"))).is_err());
+
+        assert_eq!(SnakeCase::try_from('x').unwrap(), "x");
+        assert_eq!(SnakeCase::try_from('_').unwrap(), "_");
+        assert!(SnakeCase::try_from('7').is_err());
+        assert_eq!(
+            SnakeCase::try_from('ß').unwrap_err(),
+            InvalidSnakeCase::InvalidStartChar {
+                char: 'ß',
+                offset: 0
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_snake_case() {
+        let err = |s| SnakeCase::try_from_str(s).unwrap_err();
+        assert_eq!(err(""), InvalidSnakeCase::Empty);
+        assert_eq!(
+            err("42"),
+            InvalidSnakeCase::InvalidStartChar {
+                char: '4',
+                offset: 0
+            }
+        );
+        assert_eq!(err("Python"), InvalidSnakeCase::UppercaseChar { offset: 0 });
+        assert_eq!(
+            err("hello_World"),
+            InvalidSnakeCase::UppercaseChar { offset: 6 }
+        );
+        assert_eq!(
+            err("hello-world"),
+            InvalidSnakeCase::InvalidChar {
+                char: '-',
+                offset: 5
+            }
+        );
+        assert_eq!(
+            err("stra\u{df}e"),
+            InvalidSnakeCase::InvalidChar {
+                char: '\u{df}',
+                offset: 4
+            }
+        );
+        assert_eq!(
+            err("_\u{1f40d}").to_string(),
+            "invalid character '\u{1f40d}' at offset 1: snake_case may only contain lower case letters, digits and underscores"
+        );
     }
 
     #[test]
@@ -292,6 +1317,176 @@ mod tests {
         assert!(SnakeCaseRef::try_from_str("_").is_ok());
     }
 
+    #[test]
+    fn from_str_or_panic() {
+        const ID: SnakeCaseRef<'static> = SnakeCaseRef::from_str_or_panic("hello_world");
+        assert_eq!(ID, "hello_world");
+    }
+
+    #[test]
+    fn bytes() {
+        assert!(is_snake_case_bytes(b"_a1"));
+        assert!(!is_snake_case_bytes(b""));
+        for (bytes, offset) in [
+            (&b"a\x80"[..], 1), // lone continuation byte
+            (b"ab\xC3", 2),     // truncated
+            (b"a\xE2\x28\xA1", 1),
+        ] {
+            assert_eq!(
+                validate_bytes(bytes),
+                Err(InvalidSnakeCase::InvalidChar {
+                    char: char::REPLACEMENT_CHARACTER,
+                    offset
+                }),
+                "{:?}",
+                bytes
+            );
+        }
+        assert_eq!(
+            validate_bytes("aé".as_bytes()),
+            Err(InvalidSnakeCase::InvalidChar {
+                char: 'é',
+                offset: 1
+            })
+        );
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(super::check("a_b"), Ok(()));
+        assert_eq!(super::check(""), Err((ErrorKind::Empty, 0)));
+        assert_eq!(super::check("_aä"), Err((ErrorKind::InvalidChar, 2)));
+        let err = validate("2x").unwrap_err();
+        assert_eq!(super::check("2x"), Err((err.kind(), err.offset())));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn leak() {
+        fn take_static(_: SnakeCaseRef<'static>) {}
+        let leaked = SnakeCase::try_from_str("leaked").unwrap().leak();
+        take_static(leaked);
+        assert_eq!(leaked, SnakeCaseRef::from_static("leaked"));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid snake_case: contains an upper case letter")]
+    fn from_str_or_panic_at_runtime() {
+        let input = String::from("Python");
+        SnakeCaseRef::from_str_or_panic(&input);
+    }
+
+    #[cfg(feature = "const_literals")]
+    #[test]
+    fn snake_case_lit_composes() {
+        const TABLE: &str = "users";
+        assert_eq!(snake_case_lit!(TABLE), "users");
+        assert_eq!(snake_case_lit!(concat!("app_", "users")), "app_users");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_snake_case_ref() {
+        let json = r#"["hello_world", "_42"]"#;
+        let refs: Vec<SnakeCaseRef<'_>> = serde_json::from_str(json).unwrap();
+        assert_eq!(refs, ["hello_world", "_42"]);
+        assert!(core::ptr::eq(refs[0].as_str(), &json[2..13]));
+
+        assert!(serde_json::from_str::<SnakeCaseRef<'_>>(r#""HelloWorld""#).is_err());
+    }
+
+    #[test]
+    fn cross_type_comparisons() {
+        let owned = SnakeCase::try_from_str("b").unwrap();
+        let borrowed = SnakeCaseRef::try_from_str("b").unwrap();
+        assert_eq!(owned, borrowed);
+        assert_eq!(borrowed, owned);
+        assert_eq!(String::from("b"), owned);
+        assert_eq!(String::from("b"), borrowed);
+        assert_eq!(*"b", owned);
+
+        let a = SnakeCaseRef::try_from_str("a").unwrap();
+        assert!(a < owned);
+        assert!(owned > a);
+    }
+
+    #[cfg(not(feature = "smallstr"))]
+    #[test]
+    fn boxed_storage() {
+        assert_eq!(
+            core::mem::size_of::<SnakeCase>(),
+            2 * core::mem::size_of::<usize>()
+        );
+
+        let mut string = String::with_capacity(100);
+        string.push_str("hello");
+        let snake = SnakeCase::try_from_string(string).unwrap();
+        assert_eq!(snake.into_string().capacity(), 5);
+    }
+
+    #[cfg(feature = "smallstr")]
+    #[test]
+    fn inline_storage() {
+        let mut string = String::with_capacity(100);
+        string.push_str("hello");
+        assert!(!SnakeCase::try_from_string(string).unwrap().0.spilled());
+        assert!(!SnakeCase::try_from_str("twenty_four_bytes_long_x")
+            .unwrap()
+            .0
+            .spilled());
+        assert!(SnakeCase::try_from_str("twenty_five_bytes_long_xy")
+            .unwrap()
+            .0
+            .spilled());
+    }
+
+    #[test]
+    fn generic_storage() {
+        let borrowed: SnakeCase<&str> = SnakeCase::try_new("hello_world").unwrap();
+        let owned: SnakeCase<String> = SnakeCase::try_new(String::from("hello_world")).unwrap();
+        assert_eq!(borrowed, owned);
+        assert_eq!(owned, SnakeCase::try_from_str("hello_world").unwrap());
+        assert_eq!(borrowed.words().count(), 2);
+        assert_eq!(owned.into_inner(), "hello_world");
+        assert_eq!(
+            SnakeCase::try_new("Hello").unwrap_err(),
+            InvalidSnakeCase::UppercaseChar { offset: 0 }
+        );
+
+        #[cfg(feature = "std")]
+        {
+            let set: std::collections::HashSet<SnakeCase<&str>> =
+                std::iter::once(borrowed).collect();
+            assert!(set.contains("hello_world"));
+        }
+    }
+
+    #[test]
+    fn into_string() {
+        let snake = SnakeCase::try_from_str("hello_world").unwrap();
+        assert_eq!(&*snake.clone().into_boxed_str(), "hello_world");
+        assert_eq!(snake.clone().into_bytes(), b"hello_world");
+
+        let ptr = snake.as_str().as_ptr();
+        let string = String::from(snake);
+        assert_eq!(string, "hello_world");
+        if cfg!(not(feature = "smallstr")) {
+            assert_eq!(string.as_ptr(), ptr, "should not copy");
+        }
+    }
+
+    #[test]
+    fn join() {
+        let a = SnakeCase::try_from_str("_a").unwrap();
+        let b = SnakeCaseRef::try_from_str("b_").unwrap();
+        assert_eq!(SnakeCase::join([a.as_ref(), b]).unwrap(), "_a_b_");
+        assert_eq!(SnakeCase::join(vec![&a, &a]).unwrap(), "_a__a");
+        assert_eq!(
+            SnakeCase::join(Vec::<SnakeCaseRef<'_>>::new()),
+            Err(InvalidSnakeCase::Empty)
+        );
+    }
+
     #[test]
     fn snake_case_conversions() {
         let sc = SnakeCase::try_from_str("hello_world").unwrap();
@@ -300,9 +1495,28 @@ mod tests {
         let sc2: SnakeCase = scr.to_owned();
         assert_eq!(sc2, "hello_world");
 
-        use std::collections::HashSet;
-        let mut set: HashSet<SnakeCase> = HashSet::new();
-        set.insert(SnakeCase::try_from_str("hello_world").unwrap());
-        assert!(set.contains(SnakeCaseRef::try_from_str("hello_world").unwrap().as_str()));
+        #[cfg(feature = "std")]
+        {
+            use std::collections::HashSet;
+            let mut set: HashSet<SnakeCase> = HashSet::new();
+            set.insert(SnakeCase::try_from_str("hello_world").unwrap());
+            assert!(set.contains(SnakeCaseRef::try_from_str("hello_world").unwrap().as_str()));
+        }
+    }
+
+    #[test]
+    fn from_any_case() {
+        let convert = |s| SnakeCase::from_any_case(s).unwrap();
+        assert_eq!(convert("hello_world"), "hello_world");
+        assert_eq!(convert("HelloWorld"), "hello_world");
+        assert_eq!(convert("helloWorld"), "hello_world");
+        assert_eq!(convert("hello-world"), "hello_world");
+        assert_eq!(convert("HELLO WORLD"), "hello_world");
+        assert_eq!(convert("  Hello,  World! "), "hello_world");
+        assert_eq!(convert("XMLHttpRequest"), "xml_http_request");
+        assert_eq!(convert("__private"), "private");
+        assert_eq!(convert("42 is the answer"), "_42_is_the_answer");
+        assert!(SnakeCase::from_any_case("").is_err());
+        assert!(SnakeCase::from_any_case("_-_ !").is_err());
     }
 }
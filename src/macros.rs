@@ -0,0 +1,234 @@
+/// Defines an owning and a non-owning string type that can only contain strings
+/// accepted by the given `const fn` validator, with the same API as `SnakeCase`/`SnakeCaseRef`.
+macro_rules! case_types {
+    (
+        $(#[$owned_meta:meta])*
+        pub struct $Owned:ident;
+
+        $(#[$ref_meta:meta])*
+        pub struct $Ref:ident;
+
+        error: $Error:ident,
+        validator: $validator:ident,
+        expected: $expected:literal,
+    ) => {
+        $(#[$owned_meta])*
+        #[cfg(feature = "alloc")]
+        #[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+        pub struct $Owned(alloc::string::String);
+
+        #[cfg(feature = "alloc")]
+        impl $Owned {
+            pub fn try_from_str(s: &str) -> Result<$Owned, $Error> {
+                if $validator(s) {
+                    Ok($Owned(alloc::string::String::from(s)))
+                } else {
+                    Err($Error)
+                }
+            }
+
+            pub fn try_from_string(s: alloc::string::String) -> Result<$Owned, $Error> {
+                if $validator(&s) {
+                    Ok($Owned(s))
+                } else {
+                    Err($Error)
+                }
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn as_ref(&self) -> $Ref<'_> {
+                $Ref(&self.0)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl core::convert::TryFrom<&str> for $Owned {
+            type Error = $Error;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                $Owned::try_from_str(s)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl core::convert::TryFrom<alloc::string::String> for $Owned {
+            type Error = $Error;
+
+            fn try_from(s: alloc::string::String) -> Result<Self, Self::Error> {
+                $Owned::try_from_string(s)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl core::str::FromStr for $Owned {
+            type Err = $Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $Owned::try_from_str(s)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl core::borrow::Borrow<str> for $Owned {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl core::fmt::Debug for $Owned {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.as_str().fmt(f)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl core::fmt::Display for $Owned {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.as_str().fmt(f)
+            }
+        }
+
+        #[cfg(all(feature = "alloc", feature = "serde"))]
+        impl<'de> serde::Deserialize<'de> for $Owned {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let string = <alloc::string::String as serde::Deserialize>::deserialize(deserializer)?;
+                $Owned::try_from_str(&string).map_err(|_: $Error| {
+                    serde::de::Error::custom(alloc::format!(
+                        concat!("Expected ", $expected, ", got '{}'"),
+                        string
+                    ))
+                })
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl core::cmp::PartialEq<$Owned> for &str {
+            fn eq(&self, other: &$Owned) -> bool {
+                *self == other.as_str()
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl core::cmp::PartialEq<str> for $Owned {
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl core::cmp::PartialEq<&str> for $Owned {
+            fn eq(&self, other: &&str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl core::cmp::PartialEq<alloc::string::String> for $Owned {
+            fn eq(&self, other: &alloc::string::String) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        // ----------------------------------------------------------------------------
+
+        impl core::fmt::Display for $Error {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(concat!("expected ", $expected))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl std::error::Error for $Error {}
+
+        // ----------------------------------------------------------------------------
+
+        $(#[$ref_meta])*
+        #[derive(Copy, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+        pub struct $Ref<'a>(&'a str);
+
+        impl<'a> $Ref<'a> {
+            pub const fn try_from_str(s: &str) -> Result<$Ref<'_>, $Error> {
+                if $validator(s) {
+                    Ok($Ref(s))
+                } else {
+                    Err($Error)
+                }
+            }
+
+            pub const fn as_str(&self) -> &'a str {
+                self.0
+            }
+
+            #[cfg(feature = "alloc")]
+            pub fn to_owned(&self) -> $Owned {
+                $Owned(alloc::string::String::from(self.0))
+            }
+        }
+
+        impl<'a> core::convert::TryFrom<&'a str> for $Ref<'a> {
+            type Error = $Error;
+
+            fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+                $Ref::try_from_str(s)
+            }
+        }
+
+        impl core::borrow::Borrow<str> for $Ref<'_> {
+            fn borrow(&self) -> &str {
+                self.0
+            }
+        }
+
+        impl core::fmt::Debug for $Ref<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.as_str().fmt(f)
+            }
+        }
+
+        impl core::fmt::Display for $Ref<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.as_str().fmt(f)
+            }
+        }
+
+        impl core::cmp::PartialEq<$Ref<'_>> for str {
+            fn eq(&self, other: &$Ref<'_>) -> bool {
+                self == other.0
+            }
+        }
+
+        impl core::cmp::PartialEq<$Ref<'_>> for &str {
+            fn eq(&self, other: &$Ref<'_>) -> bool {
+                *self == other.0
+            }
+        }
+
+        impl core::cmp::PartialEq<str> for $Ref<'_> {
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl core::cmp::PartialEq<&str> for $Ref<'_> {
+            fn eq(&self, other: &&str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl core::cmp::PartialEq<alloc::string::String> for $Ref<'_> {
+            fn eq(&self, other: &alloc::string::String) -> bool {
+                self.as_str() == *other
+            }
+        }
+    };
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+
+use crate::{InvalidSnakeCase, SnakeCaseStrExt as _};
+
+/// The offending character is labeled, so wrapping the error in a [`miette::Report`]
+/// with the input as source code underlines it.
+/// Use [`InvalidSnakeCase::with_source`] to also get a suggestion of what to write instead.
+impl Diagnostic for InvalidSnakeCase {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("snake_case::invalid"))
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let (len, label) = match self {
+            Self::Empty => return None,
+            Self::InvalidStartChar { char, .. } => (char.len_utf8(), "not allowed at the start"),
+            Self::InvalidChar { char, .. } => (char.len_utf8(), "not allowed in snake_case"),
+            Self::UppercaseChar { .. } => (1, "upper case"),
+        };
+        let span = LabeledSpan::new_primary_with_span(Some(label.into()), (self.offset(), len));
+        Some(Box::new(core::iter::once(span)))
+    }
+}
+
+impl InvalidSnakeCase {
+    /// Attach the input that failed to validate, for a [`Diagnostic`] with the source code
+    /// and a suggestion (from [`crate::SnakeCaseStrExt::to_snake_case_lossy`]):
+    ///
+    /// ```text
+    ///   × upper case character at offset 4: snake_case must be lower case
+    ///    ╭────
+    ///  1 │ userName
+    ///    ·     ┬
+    ///    ·     ╰── upper case
+    ///    ╰────
+    ///   help: try `user_name`
+    /// ```
+    pub fn with_source(self, input: impl Into<String>) -> SnakeCaseDiagnostic {
+        SnakeCaseDiagnostic {
+            input: input.into(),
+            error: self,
+        }
+    }
+}
+
+/// An [`InvalidSnakeCase`] together with the input, see [`InvalidSnakeCase::with_source`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnakeCaseDiagnostic {
+    input: String,
+    error: InvalidSnakeCase,
+}
+
+impl SnakeCaseDiagnostic {
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn error(&self) -> &InvalidSnakeCase {
+        &self.error
+    }
+}
+
+impl fmt::Display for SnakeCaseDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl std::error::Error for SnakeCaseDiagnostic {}
+
+impl Diagnostic for SnakeCaseDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.code()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        let suggestion = self.input.to_snake_case_lossy();
+        Some(Box::new(format!("try `{}`", suggestion)))
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.input)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.error.labels()
+    }
+}
+
+impl From<SnakeCaseDiagnostic> for InvalidSnakeCase {
+    fn from(diagnostic: SnakeCaseDiagnostic) -> Self {
+        diagnostic.error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SnakeCase;
+
+    #[test]
+    fn diagnostic() {
+        let input = "max_Länge";
+        let diagnostic = SnakeCase::try_from_str(input)
+            .unwrap_err()
+            .with_source(input);
+        let labels: Vec<LabeledSpan> = diagnostic.labels().unwrap().collect();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].offset(), 4);
+        assert_eq!(labels[0].label(), Some("upper case"));
+        assert_eq!(diagnostic.help().unwrap().to_string(), "try `max_l_nge`");
+
+        let err = SnakeCase::try_from_str("a_ä").unwrap_err();
+        let label = err.labels().unwrap().next().unwrap();
+        assert_eq!((label.offset(), label.len()), (2, 2));
+
+        assert!(InvalidSnakeCase::Empty.labels().is_none());
+    }
+}
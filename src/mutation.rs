@@ -0,0 +1,317 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+
+use crate::{validate_word, InvalidSnakeCase, SnakeCase, SnakeCaseRef, SnakeStr};
+
+/// The storages a [`SnakeCase`] can be edited in: the default one, `Box<str>` and `String`.
+///
+/// The default storage has no spare capacity, so every push reallocates.
+/// For building an identifier word by word, edit a `SnakeCase<String>` instead,
+/// which grows like any `String`, and convert it into a `SnakeCase` at the end.
+pub trait EditableStorage: AsRef<str> + private::Sealed {
+    #[doc(hidden)]
+    fn push_strs(&mut self, parts: &[&str]);
+
+    #[doc(hidden)]
+    fn truncate(&mut self, len: usize);
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::Box<str> {}
+    impl Sealed for super::String {}
+    #[cfg(feature = "smallstr")]
+    impl Sealed for crate::Repr {}
+}
+
+impl EditableStorage for String {
+    fn push_strs(&mut self, parts: &[&str]) {
+        for part in parts {
+            self.push_str(part);
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        String::truncate(self, len);
+    }
+}
+
+impl EditableStorage for Box<str> {
+    fn push_strs(&mut self, parts: &[&str]) {
+        let len = self.len() + parts.iter().map(|part| part.len()).sum::<usize>();
+        let mut string = String::with_capacity(len);
+        string.push_str(self);
+        string.push_strs(parts);
+        // No spare capacity, so this doesn't reallocate:
+        *self = string.into_boxed_str();
+    }
+
+    fn truncate(&mut self, len: usize) {
+        let mut string = core::mem::take(self).into_string();
+        string.truncate(len);
+        *self = string.into_boxed_str();
+    }
+}
+
+#[cfg(feature = "smallstr")]
+impl EditableStorage for crate::Repr {
+    fn push_strs(&mut self, parts: &[&str]) {
+        for part in parts {
+            self.push_str(part);
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        crate::Repr::truncate(self, len);
+    }
+}
+
+impl From<SnakeCase<String>> for SnakeCase {
+    fn from(snake: SnakeCase<String>) -> Self {
+        SnakeCase::from_string_unchecked(snake.into_inner())
+    }
+}
+
+/// In-place editing.
+///
+/// Each of these keeps the string valid snake_case, so nothing needs to be re-validated.
+///
+/// ```
+/// # use snake_case::SnakeCase;
+/// # fn main() -> Result<(), snake_case::InvalidSnakeCase> {
+/// let mut id = SnakeCase::try_from_str("user")?;
+/// id.push_word("id")?.push_word("v2")?;
+/// assert_eq!(id, "user_id_v2");
+/// assert_eq!(id.pop_word().as_deref(), Some("v2"));
+/// assert_eq!(id, "user_id");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See [`EditableStorage`] for building long identifiers without reallocating on every push:
+///
+/// ```
+/// # use snake_case::SnakeCase;
+/// let mut id: SnakeCase<String> = SnakeCase::try_new(String::from("table")).unwrap();
+/// for _ in 0..1000 {
+///     id.push_word("x").unwrap();
+/// }
+/// let id: SnakeCase = id.into();
+/// assert_eq!(id.len(), 5 + 2 * 1000);
+/// ```
+impl<S: EditableStorage> SnakeCase<S> {
+    /// Append an underscore and a word, which must be non-empty and consist of only lower case ASCII letters and digits.
+    ///
+    /// Error offsets refer to where the offending character would have ended up.
+    /// On error, `self` is left unchanged.
+    pub fn push_word(&mut self, word: &str) -> Result<&mut Self, InvalidSnakeCase> {
+        validate_word(word, self.as_str().len() + 1)?;
+        self.0.push_strs(&["_", word]);
+        Ok(self)
+    }
+
+    /// Append an underscore and some other snake_case.
+    pub fn push_snake(&mut self, snake: impl AsRef<SnakeStr>) -> &mut Self {
+        self.0.push_strs(&["_", snake.as_ref().as_str()]);
+        self
+    }
+
+    /// Remove the last word, along with the underscores around it.
+    ///
+    /// Returns `None` (and leaves `self` unchanged) if that would leave nothing behind,
+    /// e.g. for `foo` and `_foo`.
+    pub fn pop_word(&mut self) -> Option<String> {
+        let (end_of_rest, start_of_last) = last_word_bounds(self.as_str())?;
+        let last = self.as_str()[start_of_last..]
+            .trim_end_matches('_')
+            .to_string();
+        self.0.truncate(end_of_rest);
+        Some(last)
+    }
+
+    /// Keep only the first `n` words (and any underscores before them).
+    ///
+    /// At least one word is always kept, since snake_case can't be empty.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCase;
+    /// let mut id = SnakeCase::try_from_str("_http_server_2").unwrap();
+    /// id.truncate_words(2);
+    /// assert_eq!(id, "_http_server");
+    /// ```
+    pub fn truncate_words(&mut self, n: usize) {
+        let n = n.max(1);
+        let mut words = 0;
+        let mut end = None;
+        let bytes = self.as_str().as_bytes();
+        for (i, b) in bytes.iter().enumerate() {
+            let word_start = *b != b'_' && (i == 0 || bytes[i - 1] == b'_');
+            if word_start {
+                if words == n {
+                    end = Some(i);
+                    break;
+                }
+                words += 1;
+            }
+        }
+        if let Some(end) = end {
+            // `end` is the start of word `n + 1`; drop the underscores before it too:
+            let end = self.as_str()[..end].trim_end_matches('_').len();
+            self.0.truncate(end);
+        }
+    }
+
+    /// If `taken(self)`, append `_2`, `_3`, … until it isn't.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCase;
+    /// let existing = ["table", "table_2"];
+    /// let mut name = SnakeCase::try_from_str("table").unwrap();
+    /// name.make_unique_suffix(|s| existing.contains(&s));
+    /// assert_eq!(name, "table_3");
+    /// ```
+    ///
+    /// # Panics
+    /// If `taken` is still true after a million suffixes, which is a bug in `taken` rather than a crowded namespace.
+    pub fn make_unique_suffix(&mut self, mut taken: impl FnMut(&str) -> bool) {
+        if !taken(self.as_str()) {
+            return;
+        }
+        let base_len = self.as_str().len();
+        let candidate = first_free(self.as_str(), 2, taken);
+        self.0.push_strs(&[&candidate[base_len..]]);
+    }
+}
+
+/// A name based on `base` that isn't `taken`, for "duplicate item" and code generation.
+///
+/// Returns `base` itself if it's free. Otherwise a numeric last word is bumped,
+/// and other names get `_2`, `_3`, … appended (like [`SnakeCase::make_unique_suffix`]).
+///
+/// ```
+/// # use snake_case::{make_unique, SnakeCaseRef};
+/// let existing = ["layer", "layer_2", "layer_3"];
+/// let taken = |s: &str| existing.contains(&s);
+/// let unique = |s| make_unique(&SnakeCaseRef::try_from_str(s).unwrap(), &taken);
+/// assert_eq!(unique("layer"), "layer_4");
+/// assert_eq!(unique("layer_2"), "layer_4");
+/// assert_eq!(unique("layer_9"), "layer_9");
+/// ```
+///
+/// # Panics
+/// If `taken` is still true after a million numbers, like [`SnakeCase::make_unique_suffix`].
+pub fn make_unique(base: &SnakeCaseRef<'_>, taken: &impl Fn(&str) -> bool) -> SnakeCase {
+    let s = base.as_str();
+    if !taken(s) {
+        return SnakeCase::from_string_unchecked(s.into());
+    }
+    let numbered = last_word_bounds(s).and_then(|(end_of_rest, start_of_last)| {
+        let n: u64 = s[start_of_last..].parse().ok()?;
+        Some((&s[..end_of_rest], n.checked_add(1)?))
+    });
+    let (stem, first) = numbered.unwrap_or((s, 2));
+    SnakeCase::from_string_unchecked(first_free(stem, first, taken))
+}
+
+/// How many numbers [`first_free`] tries before giving up.
+const MAX_ATTEMPTS: u64 = 1_000_000;
+
+/// `{stem}_{n}` for the first `n` (counting from `first`) that isn't `taken`.
+fn first_free(stem: &str, first: u64, mut taken: impl FnMut(&str) -> bool) -> String {
+    use core::fmt::Write as _;
+    let mut candidate = String::from(stem);
+    for n in (first..).take(MAX_ATTEMPTS as usize) {
+        candidate.truncate(stem.len());
+        write!(candidate, "_{}", n).ok();
+        if !taken(&candidate) {
+            return candidate;
+        }
+    }
+    panic!(
+        "{:?}: {} numbered names in a row were taken",
+        stem, MAX_ATTEMPTS
+    );
+}
+
+/// Where the part before the underscores preceding the last word ends, and where the last word starts.
+///
+/// `None` if there is no last word, or nothing but underscores before it.
+fn last_word_bounds(s: &str) -> Option<(usize, usize)> {
+    let trimmed = s.trim_end_matches('_');
+    let start_of_last = trimmed.rfind('_')? + 1;
+    let end_of_rest = trimmed[..start_of_last].trim_end_matches('_').len();
+    if end_of_rest == 0 {
+        None
+    } else {
+        Some((end_of_rest, start_of_last))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutation() {
+        let mut id = SnakeCase::try_from_str("a").unwrap();
+        assert_eq!(
+            id.push_word("B").unwrap_err(),
+            InvalidSnakeCase::UppercaseChar { offset: 2 }
+        );
+        assert_eq!(id.push_word("").unwrap_err(), InvalidSnakeCase::Empty);
+        id.push_snake(SnakeCase::try_from_str("_b").unwrap());
+        assert_eq!(id, "a__b");
+        assert_eq!(id.pop_word().unwrap(), "b");
+        assert_eq!(id, "a");
+        assert_eq!(id.pop_word(), None);
+
+        let mut id = SnakeCase::try_from_str("_x__y_z_").unwrap();
+        assert_eq!(id.pop_word().unwrap(), "z");
+        assert_eq!(id, "_x__y");
+        id.truncate_words(0);
+        assert_eq!(id, "_x");
+        assert_eq!(id.pop_word(), None);
+
+        let mut id = SnakeCase::try_from_str("x").unwrap();
+        id.make_unique_suffix(|_| false);
+        assert_eq!(id, "x");
+    }
+
+    #[test]
+    fn string_storage() {
+        let mut id: SnakeCase<String> = SnakeCase::try_new(String::from("a")).unwrap();
+        id.push_word("b")
+            .unwrap()
+            .push_snake(SnakeStr::try_from_str("_c").unwrap());
+        assert_eq!(id, "a_b__c");
+        id.truncate_words(2);
+        assert_eq!(id.pop_word().unwrap(), "b");
+        id.make_unique_suffix(|s| s == "a");
+        assert_eq!(id, "a_2");
+
+        let string = id.into_inner();
+        assert!(string.capacity() >= "a_b__c".len(), "edited in place");
+        let id: SnakeCase = SnakeCase::try_new(string).unwrap().into();
+        assert_eq!(id, "a_2");
+    }
+
+    #[test]
+    fn make_unique() {
+        let taken = |s: &str| ["_2", "v_1", "v_01", "v__1", "a_2b"].contains(&s);
+        let unique = |s| super::make_unique(&SnakeCaseRef::try_from_str(s).unwrap(), &taken);
+        assert_eq!(unique("_2"), "_2_2"); // no words before the number
+        assert_eq!(unique("v_1"), "v_2");
+        assert_eq!(unique("v_01"), "v_2");
+        assert_eq!(unique("v__1"), "v_2");
+        assert_eq!(unique("a_2b"), "a_2b_2");
+    }
+
+    #[test]
+    #[should_panic(expected = "numbered names in a row were taken")]
+    fn make_unique_gives_up() {
+        super::make_unique(&SnakeCaseRef::try_from_str("a").unwrap(), &|_| true);
+    }
+}
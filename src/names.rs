@@ -0,0 +1,117 @@
+use core::fmt;
+
+use crate::{InvalidSnakeCase, SnakeCaseRef};
+
+/// A type with a snake_case name, e.g. for table names, metric names, or log targets.
+///
+/// Usually implemented with `#[derive(SnakeCaseTypeName)]` (requires the `"derive"` feature),
+/// which converts the name of the type to snake_case at compile time (`MyHttpServer` -> `my_http_server`).
+///
+/// ```
+/// # use snake_case::{SnakeCaseRef, SnakeCaseTypeName};
+/// struct MyHttpServer;
+///
+/// impl SnakeCaseTypeName for MyHttpServer {
+///     const TYPE_NAME: SnakeCaseRef<'static> = SnakeCaseRef::from_str_or_panic("my_http_server");
+/// }
+/// ```
+pub trait SnakeCaseTypeName {
+    /// The name of the type in snake_case.
+    const TYPE_NAME: SnakeCaseRef<'static>;
+}
+
+/// A struct with snake_case field names, e.g. for building column lists.
+///
+/// Usually implemented with `#[derive(SnakeCaseFields)]` (requires the `"derive"` feature),
+/// which fails to compile if any field name is not valid snake_case.
+///
+/// ```
+/// # use snake_case::{SnakeCaseFields, SnakeCaseRef};
+/// struct User {
+///     id: u64,
+///     display_name: String,
+/// }
+///
+/// impl SnakeCaseFields for User {
+///     const FIELDS: &'static [SnakeCaseRef<'static>] = &[
+///         SnakeCaseRef::from_str_or_panic("id"),
+///         SnakeCaseRef::from_str_or_panic("display_name"),
+///     ];
+/// }
+/// ```
+pub trait SnakeCaseFields {
+    /// The names of all the fields, in declaration order.
+    const FIELDS: &'static [SnakeCaseRef<'static>];
+}
+
+/// An enum with snake_case variant names, e.g. for mapping variants to wire identifiers.
+///
+/// Usually implemented with `#[derive(SnakeCaseVariants)]` (requires the `"derive"` feature),
+/// which converts the variant names from PascalCase at compile time (`KeyDown` -> `key_down`).
+///
+/// ```
+/// # use snake_case::{SnakeCaseRef, SnakeCaseVariants};
+/// enum Event {
+///     KeyDown,
+///     KeyUp,
+/// }
+///
+/// impl SnakeCaseVariants for Event {
+///     const VARIANTS: &'static [SnakeCaseRef<'static>] = &[
+///         SnakeCaseRef::from_str_or_panic("key_down"),
+///         SnakeCaseRef::from_str_or_panic("key_up"),
+///     ];
+///
+///     fn variant_name(&self) -> SnakeCaseRef<'static> {
+///         match self {
+///             Event::KeyDown => Self::VARIANTS[0],
+///             Event::KeyUp => Self::VARIANTS[1],
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Event::KeyUp.variant_name(), "key_up");
+/// ```
+pub trait SnakeCaseVariants {
+    /// The names of all the variants, in declaration order.
+    const VARIANTS: &'static [SnakeCaseRef<'static>];
+
+    /// The name of this variant.
+    fn variant_name(&self) -> SnakeCaseRef<'static>;
+}
+
+/// A string field that is not valid snake_case, as returned by the `validate_idents`
+/// method of `#[derive(ValidateSnakeCaseFields)]` (requires the `"derive"` feature).
+///
+/// ```
+/// # use snake_case::{FieldError, InvalidSnakeCase};
+/// let err = FieldError {
+///     field: "table",
+///     error: InvalidSnakeCase::UppercaseChar { offset: 0 },
+/// };
+/// assert_eq!(
+///     err.to_string(),
+///     "field `table`: upper case character at offset 0: snake_case must be lower case"
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FieldError {
+    /// The name of the field.
+    pub field: &'static str,
+
+    /// What is wrong with its value.
+    pub error: InvalidSnakeCase,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "field `{}`: {}", self.field, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
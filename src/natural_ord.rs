@@ -0,0 +1,135 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use crate::SnakeStr;
+
+impl SnakeStr {
+    /// Compare with numbers ordered by value, so `item_2` comes before `item_10`.
+    ///
+    /// ```
+    /// # use snake_case::SnakeStr;
+    /// use std::cmp::Ordering;
+    /// let a = SnakeStr::try_from_str("item_2").unwrap();
+    /// let b = SnakeStr::try_from_str("item_10").unwrap();
+    /// assert_eq!(a.cmp_natural(b), Ordering::Less);
+    /// assert_eq!(a.cmp(b), Ordering::Greater);
+    /// ```
+    ///
+    /// Numbers that are equal in value but differ in leading zeros (`v01` and `v1`)
+    /// fall back to the normal ordering, so this is only `Equal` for equal strings.
+    pub fn cmp_natural(&self, other: &SnakeStr) -> Ordering {
+        cmp_natural(self.as_str().as_bytes(), other.as_str().as_bytes())
+            .then_with(|| self.as_str().cmp(other.as_str()))
+    }
+}
+
+fn cmp_natural(mut a: &[u8], mut b: &[u8]) -> Ordering {
+    loop {
+        match (a.first(), b.first()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let (a_num, a_rest) = split_number(a);
+                let (b_num, b_rest) = split_number(b);
+                let ordering = a_num.len().cmp(&b_num.len()).then(a_num.cmp(b_num));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a = a_rest;
+                b = b_rest;
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(y);
+                }
+                a = &a[1..];
+                b = &b[1..];
+            }
+        }
+    }
+}
+
+/// Split off the leading digits, without their leading zeros.
+fn split_number(s: &[u8]) -> (&[u8], &[u8]) {
+    let len = s.iter().take_while(|b| b.is_ascii_digit()).count();
+    let zeros = s[..len].iter().take_while(|&&b| b == b'0').count();
+    (&s[zeros..len], &s[len..])
+}
+
+/// Orders the wrapped snake_case with [`SnakeStr::cmp_natural`], so `item_2 < item_10`.
+///
+/// ```
+/// # use snake_case::{NaturalOrd, SnakeCaseRef};
+/// let mut items = vec!["item_10", "item_2", "item_1"];
+/// items.sort_by_key(|s| NaturalOrd(SnakeCaseRef::try_from_str(s).unwrap()));
+/// assert_eq!(items, ["item_1", "item_2", "item_10"]);
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct NaturalOrd<T>(pub T);
+
+impl<T: AsRef<SnakeStr>> PartialEq for NaturalOrd<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_ref() == other.0.as_ref()
+    }
+}
+
+impl<T: AsRef<SnakeStr>> Eq for NaturalOrd<T> {}
+
+impl<T: AsRef<SnakeStr>> Hash for NaturalOrd<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ref().hash(state);
+    }
+}
+
+impl<T: AsRef<SnakeStr>> PartialOrd for NaturalOrd<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AsRef<SnakeStr>> Ord for NaturalOrd<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.as_ref().cmp_natural(other.0.as_ref())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for NaturalOrd<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for NaturalOrd<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn natural_order() {
+        let cmp = |a, b| {
+            SnakeStr::try_from_str(a)
+                .unwrap()
+                .cmp_natural(SnakeStr::try_from_str(b).unwrap())
+        };
+        assert_eq!(cmp("item_2", "item_10"), Ordering::Less);
+        assert_eq!(cmp("item_10", "item_10"), Ordering::Equal);
+        assert_eq!(cmp("v2_b", "v2_a"), Ordering::Greater);
+        assert_eq!(cmp("v2", "v2_a"), Ordering::Less);
+        assert_eq!(cmp("v002", "v3"), Ordering::Less);
+        assert_eq!(cmp("v01", "v1"), Ordering::Less); // same value, so by bytes
+        assert_eq!(cmp("a9", "ab"), Ordering::Less);
+        assert_eq!(
+            cmp("x_99999999999999999999999", "x_100000000000000000000000"),
+            Ordering::Less
+        );
+    }
+}
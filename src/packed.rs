@@ -0,0 +1,213 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt;
+
+use crate::{SnakeCase, SnakeStr};
+
+/// A snake_case string packed into 6 bits per character, for keeping lots of identifiers in memory.
+///
+/// snake_case only uses 37 different characters (`_`, `a-z` and `0-9`), so four characters fit in three bytes.
+/// The packed bytes start with the number of characters (as a LEB128 varint), which makes
+/// a typical 30 character identifier 24 bytes instead of 30.
+///
+/// ```
+/// # use snake_case::{PackedSnakeCase, SnakeCaseRef};
+/// let name = SnakeCaseRef::try_from_str("customer_relationship_manager").unwrap();
+/// let packed = name.pack();
+/// assert_eq!(packed.as_bytes().len(), 23);
+/// assert_eq!(packed.unpack(), name);
+///
+/// let restored = PackedSnakeCase::from_bytes(packed.as_bytes()).unwrap();
+/// assert_eq!(restored, packed);
+/// ```
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct PackedSnakeCase(Box<[u8]>);
+
+const BITS: u32 = 6;
+
+fn encode_char(c: u8) -> u8 {
+    match c {
+        b'_' => 0,
+        b'a'..=b'z' => c - b'a' + 1,
+        _ => c - b'0' + 27,
+    }
+}
+
+fn decode_char(code: u8) -> Option<u8> {
+    match code {
+        0 => Some(b'_'),
+        1..=26 => Some(code - 1 + b'a'),
+        27..=36 => Some(code - 27 + b'0'),
+        _ => None,
+    }
+}
+
+impl SnakeStr {
+    /// See [`PackedSnakeCase`].
+    pub fn pack(&self) -> PackedSnakeCase {
+        let len = self.len();
+        let mut bytes = Vec::with_capacity(5 + (len * BITS as usize).div_ceil(8));
+
+        let mut n = len;
+        loop {
+            let byte = (n & 0x7f) as u8;
+            n >>= 7;
+            if n == 0 {
+                bytes.push(byte);
+                break;
+            }
+            bytes.push(byte | 0x80);
+        }
+
+        let (mut acc, mut acc_bits) = (0_u32, 0);
+        for &c in self.as_bytes() {
+            acc = (acc << BITS) | u32::from(encode_char(c));
+            acc_bits += BITS;
+            while acc_bits >= 8 {
+                acc_bits -= 8;
+                bytes.push((acc >> acc_bits) as u8);
+            }
+        }
+        if acc_bits > 0 {
+            bytes.push((acc << (8 - acc_bits)) as u8);
+        }
+
+        PackedSnakeCase(bytes.into_boxed_slice())
+    }
+}
+
+impl PackedSnakeCase {
+    /// The encoded bytes, for storing elsewhere and restoring with [`Self::from_bytes`].
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Restore from [`Self::as_bytes`].
+    ///
+    /// Returns `None` if the bytes are not the packed encoding of a valid snake_case string.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let packed = Self(bytes.into());
+        let unpacked = packed.try_unpack()?;
+        // Reject trailing bytes and non-zero padding, so that every string has exactly one encoding:
+        if unpacked.pack() == packed {
+            Some(packed)
+        } else {
+            None
+        }
+    }
+
+    /// The number of characters.
+    pub fn len(&self) -> usize {
+        self.header().map_or(0, |(len, _)| len)
+    }
+
+    /// Always `false`: snake_case is never empty.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn unpack(&self) -> SnakeCase {
+        self.try_unpack()
+            .expect("PackedSnakeCase is always a valid encoding")
+    }
+
+    /// The length and the number of bytes it is encoded in.
+    fn header(&self) -> Option<(usize, usize)> {
+        let mut len = 0_usize;
+        for (i, &byte) in self.0.iter().enumerate() {
+            let shift = 7 * i as u32;
+            if shift >= usize::BITS {
+                return None;
+            }
+            len |= usize::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Some((len, i + 1));
+            }
+        }
+        None
+    }
+
+    fn try_unpack(&self) -> Option<SnakeCase> {
+        let (len, header_len) = self.header()?;
+        let data = &self.0[header_len..];
+        if len > data.len() * 8 / BITS as usize {
+            return None;
+        }
+        let mut string = String::with_capacity(len);
+        let (mut acc, mut acc_bits) = (0_u32, 0);
+        let mut data = data.iter();
+        while string.len() < len {
+            if acc_bits < BITS {
+                acc = (acc << 8) | u32::from(*data.next()?);
+                acc_bits += 8;
+            }
+            acc_bits -= BITS;
+            let code = ((acc >> acc_bits) & 0x3f) as u8;
+            string.push(char::from(decode_char(code)?));
+        }
+        crate::validate(&string).ok()?;
+        Some(SnakeCase::from_string_unchecked(string))
+    }
+}
+
+impl From<&SnakeStr> for PackedSnakeCase {
+    fn from(snake: &SnakeStr) -> Self {
+        snake.pack()
+    }
+}
+
+impl From<&PackedSnakeCase> for SnakeCase {
+    fn from(packed: &PackedSnakeCase) -> Self {
+        packed.unpack()
+    }
+}
+
+impl fmt::Debug for PackedSnakeCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.unpack().as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for PackedSnakeCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.unpack().as_str().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for s in ["a", "_", "ab", "abc", "user_id", "_9_z", "a0123456789_xyz"] {
+            let snake = SnakeStr::try_from_str(s).unwrap();
+            let packed = snake.pack();
+            assert_eq!(packed.unpack(), s);
+            assert_eq!(packed.len(), s.len());
+            assert_eq!(packed.as_bytes().len(), 1 + (s.len() * 6).div_ceil(8));
+            assert_eq!(PackedSnakeCase::from_bytes(packed.as_bytes()), Some(packed));
+        }
+
+        let long = "a".repeat(200);
+        let packed = SnakeStr::try_from_str(&long).unwrap().pack();
+        assert_eq!(packed.as_bytes()[..2], [0xc8, 0x01]);
+        assert_eq!(packed.unpack(), long.as_str());
+    }
+
+    #[test]
+    fn from_bytes() {
+        let packed = SnakeStr::try_from_str("ab").unwrap().pack();
+        let mut bytes = packed.as_bytes().to_vec();
+        assert_eq!(bytes, [2, 0b0000_0100, 0b0010_0000]);
+
+        bytes.push(0);
+        assert_eq!(PackedSnakeCase::from_bytes(&bytes), None); // trailing byte
+        assert_eq!(PackedSnakeCase::from_bytes(&[2, 4, 0x21]), None); // non-zero padding
+        assert_eq!(PackedSnakeCase::from_bytes(&[1, 27 << 2]), None); // starts with a digit
+        assert_eq!(PackedSnakeCase::from_bytes(&[1, 63 << 2]), None); // not a character
+        assert_eq!(PackedSnakeCase::from_bytes(&[0]), None); // empty
+        assert_eq!(PackedSnakeCase::from_bytes(&[3, 4]), None); // too short
+        assert_eq!(PackedSnakeCase::from_bytes(&[]), None);
+        assert_eq!(PackedSnakeCase::from_bytes(&[0xff; 12]), None); // overlong length
+    }
+}
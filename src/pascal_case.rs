@@ -0,0 +1,156 @@
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+
+#[cfg(feature = "alloc")]
+use crate::{convert, SnakeCase, SnakeCaseRef, SnakeStr};
+
+/// Is the given string a non-empty PascalCase string?
+/// In particular, does it match  ^[A-Z][A-Za-z0-9]*$  ?
+pub const fn is_pascal_case(string: &str) -> bool {
+    let bytes = string.as_bytes();
+    if bytes.is_empty() || !bytes[0].is_ascii_uppercase() {
+        return false;
+    }
+    let mut i = 1;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_alphanumeric() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Only one possible error: the given string was not valid PascalCase.
+#[derive(Clone, Debug)]
+pub struct InvalidPascalCase;
+
+case_types! {
+    /// An owning string type that can only contain valid PascalCase.
+    /// In other words, it always matches  ^[A-Z][A-Za-z0-9]*$
+    /// * Non-empty
+    /// * Starts with an upper case ASCII letter
+    /// * Contains only ASCII letters and digits
+    pub struct PascalCase;
+
+    /// An non-owning string type that can only refer to string containing valid PascalCase.
+    /// In other words, it always matches  ^[A-Z][A-Za-z0-9]*$
+    /// * Non-empty
+    /// * Starts with an upper case ASCII letter
+    /// * Contains only ASCII letters and digits
+    pub struct PascalCaseRef;
+
+    error: InvalidPascalCase,
+    validator: is_pascal_case,
+    expected: "PascalCase",
+}
+
+#[cfg(feature = "alloc")]
+impl PascalCase {
+    /// `MyHttpServer` -> `my_http_server`
+    pub fn to_snake_case(&self) -> SnakeCase {
+        self.as_ref().to_snake_case()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PascalCaseRef<'_> {
+    /// `MyHttpServer` -> `my_http_server`
+    pub fn to_snake_case(&self) -> SnakeCase {
+        // Never fails, since PascalCase always starts with a letter.
+        SnakeCase::from_any_case(self.as_str()).expect("PascalCase starts with a letter")
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SnakeStr {
+    /// `my_http_server` -> `MyHttpServer`
+    ///
+    /// Fails for strings without a leading letter, like `_` or `_42`.
+    pub fn to_pascal_case(&self) -> Result<PascalCase, InvalidPascalCase> {
+        PascalCase::try_from_string(convert::join_camel_case(self.words(), true))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<PascalCaseRef<'_>> for SnakeCase {
+    fn from(pascal: PascalCaseRef<'_>) -> Self {
+        pascal.to_snake_case()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&PascalCase> for SnakeCase {
+    fn from(pascal: &PascalCase) -> Self {
+        pascal.to_snake_case()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<PascalCase> for SnakeCase {
+    fn from(pascal: PascalCase) -> Self {
+        pascal.to_snake_case()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<SnakeCaseRef<'_>> for PascalCase {
+    type Error = InvalidPascalCase;
+
+    fn try_from(snake: SnakeCaseRef<'_>) -> Result<Self, Self::Error> {
+        snake.to_pascal_case()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&SnakeCase> for PascalCase {
+    type Error = InvalidPascalCase;
+
+    fn try_from(snake: &SnakeCase) -> Result<Self, Self::Error> {
+        snake.to_pascal_case()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<SnakeCase> for PascalCase {
+    type Error = InvalidPascalCase;
+
+    fn try_from(snake: SnakeCase) -> Result<Self, Self::Error> {
+        snake.to_pascal_case()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pascal_case() {
+        assert!(is_pascal_case("HelloWorld42"));
+        assert!(is_pascal_case("H"));
+        assert!(!is_pascal_case(""));
+        assert!(!is_pascal_case("helloWorld"));
+        assert!(!is_pascal_case("Hello_World"));
+        assert!(PascalCase::try_from_str("42").is_err());
+    }
+
+    #[test]
+    fn pascal_snake_conversions() {
+        let snake = SnakeCase::try_from_str("my_http_server").unwrap();
+        let pascal = snake.to_pascal_case().unwrap();
+        assert_eq!(pascal, "MyHttpServer");
+        assert_eq!(pascal.to_snake_case(), snake);
+
+        let pascal = PascalCaseRef::try_from_str("HTTPServer").unwrap();
+        assert_eq!(pascal.to_snake_case(), "http_server");
+
+        assert!(SnakeCase::try_from_str("_")
+            .unwrap()
+            .to_pascal_case()
+            .is_err());
+        assert!(SnakeCase::try_from_str("_2d")
+            .unwrap()
+            .to_pascal_case()
+            .is_err());
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    ffi::OsStr,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::{InvalidSnakeCase, SnakeCase, SnakeCaseRef, SnakeStr};
+
+impl AsRef<Path> for SnakeStr {
+    fn as_ref(&self) -> &Path {
+        Path::new(self.as_str())
+    }
+}
+
+impl<S: AsRef<str>> AsRef<Path> for SnakeCase<S> {
+    fn as_ref(&self) -> &Path {
+        Path::new(self.as_str())
+    }
+}
+
+impl AsRef<Path> for SnakeCaseRef<'_> {
+    fn as_ref(&self) -> &Path {
+        Path::new(self.as_str())
+    }
+}
+
+impl SnakeStr {
+    /// A file name for this identifier.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCaseRef;
+    /// # use std::path::Path;
+    /// let module = SnakeCaseRef::try_from_str("http_server").unwrap();
+    /// assert_eq!(module.with_extension("rs"), Path::new("http_server.rs"));
+    /// assert_eq!(Path::new("src").join(module), Path::new("src/http_server"));
+    /// ```
+    pub fn with_extension(&self, extension: impl AsRef<OsStr>) -> PathBuf {
+        Path::new(self.as_str()).with_extension(extension)
+    }
+}
+
+impl<'a> SnakeCaseRef<'a> {
+    /// Validate an `OsStr`, like a file name from a directory listing, without converting it to a `str` first.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCaseRef;
+    /// # use std::ffi::OsStr;
+    /// assert_eq!(SnakeCaseRef::try_from_os_str(OsStr::new("src")).unwrap(), "src");
+    /// assert!(SnakeCaseRef::try_from_os_str(OsStr::new("Cargo.toml")).is_err());
+    /// ```
+    ///
+    /// Parts that are not Unicode are reported as [`char::REPLACEMENT_CHARACTER`].
+    pub fn try_from_os_str(s: &'a OsStr) -> Result<Self, InvalidSnakeCase> {
+        // The platform encoding is a superset of UTF-8 in which ASCII is ASCII,
+        // so valid snake_case means the same on all platforms.
+        SnakeCaseRef::try_from_bytes(s.as_encoded_bytes())
+    }
+}
+
+/// Why [`SnakeCase::try_from_path_stem`] failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvalidPathStem {
+    /// The path has no file name, like `..` or `/`.
+    NoStem,
+
+    /// The file stem is not valid UTF-8.
+    NotUnicode,
+
+    NotSnakeCase(InvalidSnakeCase),
+}
+
+impl fmt::Display for InvalidPathStem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoStem => write!(f, "the path has no file name"),
+            Self::NotUnicode => write!(f, "the file name is not valid UTF-8"),
+            Self::NotSnakeCase(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for InvalidPathStem {}
+
+impl From<InvalidSnakeCase> for InvalidPathStem {
+    fn from(err: InvalidSnakeCase) -> Self {
+        Self::NotSnakeCase(err)
+    }
+}
+
+impl SnakeCase {
+    /// The file name without its extension, e.g. `http_server` for `src/http_server.rs`.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCase;
+    /// # use std::path::Path;
+    /// let stem = SnakeCase::try_from_path_stem(Path::new("src/http_server.rs")).unwrap();
+    /// assert_eq!(stem, "http_server");
+    /// assert!(SnakeCase::try_from_path_stem(Path::new("src/HttpServer.rs")).is_err());
+    /// ```
+    pub fn try_from_path_stem(path: &Path) -> Result<SnakeCase, InvalidPathStem> {
+        let stem = path.file_stem().ok_or(InvalidPathStem::NoStem)?;
+        let stem = stem.to_str().ok_or(InvalidPathStem::NotUnicode)?;
+        Ok(SnakeCase::try_from_str(stem)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_stem() {
+        let stem = |path: &str| SnakeCase::try_from_path_stem(Path::new(path));
+        assert_eq!(stem("a/b/c_d.json").unwrap(), "c_d");
+        assert!(stem("a/b/c_d.tar.gz").is_err()); // the stem is `c_d.tar`
+        assert_eq!(stem("c_d").unwrap(), "c_d");
+        assert_eq!(stem("/"), Err(InvalidPathStem::NoStem));
+        assert_eq!(
+            stem(".hidden"),
+            Err(InvalidPathStem::NotSnakeCase(
+                InvalidSnakeCase::InvalidStartChar {
+                    char: '.',
+                    offset: 0
+                }
+            ))
+        );
+
+        let snake = SnakeCase::try_from_str("mod_name").unwrap();
+        let path: &Path = AsRef::as_ref(&snake);
+        assert_eq!(path.join("mod.rs"), Path::new("mod_name/mod.rs"));
+        assert_eq!(snake.with_extension("json"), Path::new("mod_name.json"));
+    }
+}
@@ -0,0 +1,215 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+use crate::{validate_word, InvalidSnakeCase, SnakeStr};
+
+/// A pattern of words and wildcards for matching snake_case identifiers, like `http_*_count`.
+///
+/// The pattern is split on `_` into words, each of which is one of:
+/// * `?`: matches exactly one word
+/// * `*`: matches one or more words
+/// * anything else: matches a word equal to it
+///
+/// ```
+/// # use snake_case::{SnakeCaseRef, SnakePattern};
+/// let pattern = SnakePattern::new("http_*_count").unwrap();
+/// let matches = |s| pattern.matches(&SnakeCaseRef::try_from_str(s).unwrap());
+/// assert!(matches("http_request_count"));
+/// assert!(matches("http_request_error_count"));
+/// assert!(!matches("http_count"));
+/// assert!(!matches("http_request_count_total"));
+///
+/// let pattern = SnakePattern::new("db_?_latency").unwrap();
+/// assert!(pattern.matches(&SnakeCaseRef::try_from_str("db_postgres_latency").unwrap()));
+/// assert!(!pattern.matches(&SnakeCaseRef::try_from_str("db_read_replica_latency").unwrap()));
+/// ```
+///
+/// Identifiers are matched word by word (see [`SnakeStr::words`]),
+/// so their leading, trailing and repeated underscores are ignored: `_http__request_count` matches too.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct SnakePattern {
+    pattern: Box<str>,
+    tokens: Vec<Token>,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Token {
+    Word { start: usize, end: usize },
+    One,
+    OneOrMore,
+}
+
+/// Why a string is not a valid [`SnakePattern`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvalidSnakePattern {
+    /// The pattern is empty, or has leading, trailing or consecutive underscores.
+    EmptyWord { offset: usize },
+
+    /// A word that is neither a wildcard nor valid in snake_case.
+    InvalidWord(InvalidSnakeCase),
+}
+
+impl fmt::Display for InvalidSnakePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyWord { offset } => write!(
+                f,
+                "empty word at offset {}: words in a pattern are separated by single underscores",
+                offset
+            ),
+            Self::InvalidWord(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidSnakePattern {}
+
+impl SnakePattern {
+    pub fn new(pattern: &str) -> Result<Self, InvalidSnakePattern> {
+        let mut tokens = Vec::new();
+        let mut start = 0;
+        for word in pattern.split('_') {
+            let end = start + word.len();
+            tokens.push(match word {
+                "" => return Err(InvalidSnakePattern::EmptyWord { offset: start }),
+                "?" => Token::One,
+                "*" => Token::OneOrMore,
+                word => {
+                    validate_word(word, start).map_err(InvalidSnakePattern::InvalidWord)?;
+                    Token::Word { start, end }
+                }
+            });
+            start = end + 1;
+        }
+        Ok(Self {
+            pattern: pattern.into(),
+            tokens,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    pub fn matches(&self, ident: impl AsRef<SnakeStr>) -> bool {
+        let words: Vec<&str> = ident.as_ref().words().collect();
+        self.matches_words(&words)
+    }
+
+    /// The usual glob matching, backtracking only to the last `*`, so it is linear in practice
+    /// and `O(tokens * words)` at worst.
+    fn matches_words(&self, words: &[&str]) -> bool {
+        let (mut t, mut w) = (0, 0);
+        // The token after the last `*`, and the word its match currently ends before:
+        let mut last_star = None;
+        while w < words.len() {
+            let matched = match self.tokens.get(t) {
+                Some(Token::Word { start, end }) => words[w] == &self.pattern[*start..*end],
+                Some(Token::One) => true,
+                Some(Token::OneOrMore) => {
+                    last_star = Some((t + 1, w + 1));
+                    true
+                }
+                None => false,
+            };
+            if matched {
+                t += 1;
+                w += 1;
+            } else if let Some((star_t, star_w)) = last_star {
+                // Let the `*` match one more word and try again from there:
+                last_star = Some((star_t, star_w + 1));
+                t = star_t;
+                w = star_w + 1;
+            } else {
+                return false;
+            }
+        }
+        // Every remaining token needs at least one word:
+        t == self.tokens.len()
+    }
+}
+
+impl TryFrom<&str> for SnakePattern {
+    type Error = InvalidSnakePattern;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl FromStr for SnakePattern {
+    type Err = InvalidSnakePattern;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl fmt::Debug for SnakePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pattern.fmt(f)
+    }
+}
+
+impl fmt::Display for SnakePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pattern.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern() {
+        let matches = |pattern, s| {
+            SnakePattern::new(pattern)
+                .unwrap()
+                .matches(SnakeStr::try_from_str(s).unwrap())
+        };
+        assert!(matches("*", "a_b_c"));
+        assert!(matches("a_*_*", "a_b_c"));
+        assert!(!matches("a_*_*", "a_b"));
+        assert!(matches("*_c_*", "a_b_c_d_c_e"));
+        assert!(matches("?_?", "_a__b_"));
+        assert!(!matches("?", "a_b"));
+        assert!(!matches("a", "ab"));
+
+        assert_eq!(
+            SnakePattern::new("a__b"),
+            Err(InvalidSnakePattern::EmptyWord { offset: 2 })
+        );
+        assert_eq!(
+            SnakePattern::new(""),
+            Err(InvalidSnakePattern::EmptyWord { offset: 0 })
+        );
+        assert_eq!(
+            SnakePattern::new("a_B*"),
+            Err(InvalidSnakePattern::InvalidWord(
+                InvalidSnakeCase::UppercaseChar { offset: 2 }
+            ))
+        );
+        assert!(SnakePattern::new("a_b*").is_err());
+    }
+
+    #[test]
+    fn many_stars() {
+        let matches = |pattern, s: &str| {
+            SnakePattern::new(pattern)
+                .unwrap()
+                .matches(SnakeStr::try_from_str(s).unwrap())
+        };
+        assert!(matches("*_a", "a_a_a"));
+        assert!(matches("*_a_?", "a_a_b_a_b"));
+        assert!(!matches("*_a_?", "a_a_b_a"));
+        assert!(matches("?_*_b", "a_b_b"));
+        assert!(!matches("?_*_b", "a_b"));
+
+        // Exponential for naive backtracking:
+        let ident = ["a"; 400].join("_");
+        assert!(!matches("*_*_*_*_*_*_*_*_z", &ident));
+        assert!(matches("*_*_*_*_*_*_*_*_a", &ident));
+    }
+}
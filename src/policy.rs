@@ -0,0 +1,428 @@
+use core::fmt;
+
+use crate::{validate, InvalidSnakeCase};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::{SnakeCase, SnakeStr},
+    core::{convert::TryFrom, marker::PhantomData, ops::Deref, str::FromStr},
+};
+
+/// Extra rules on top of snake_case, for when your style guide is stricter than `^[_a-z][_a-z0-9]*$`.
+///
+/// The default allows everything [`crate::is_snake_case`] does.
+/// The builder methods are `const`, so a policy can be a `const` item:
+///
+/// ```
+/// # use snake_case::{Policy, PolicyViolation};
+/// const STYLE_GUIDE: Policy = Policy::new()
+///     .allow_consecutive_underscores(false)
+///     .allow_trailing_underscore(false)
+///     .max_len(32);
+///
+/// assert!(STYLE_GUIDE.validate("user_id").is_ok());
+/// assert_eq!(
+///     STYLE_GUIDE.validate("user__id"),
+///     Err(PolicyViolation::ConsecutiveUnderscores { offset: 4 })
+/// );
+/// assert_eq!(STYLE_GUIDE.validate("user_"), Err(PolicyViolation::TrailingUnderscore));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Policy {
+    leading_underscore: bool,
+    consecutive_underscores: bool,
+    trailing_underscore: bool,
+    max_len: Option<usize>,
+    min_words: usize,
+}
+
+impl Policy {
+    /// Allows everything that is snake_case.
+    pub const fn new() -> Self {
+        Self {
+            leading_underscore: true,
+            consecutive_underscores: true,
+            trailing_underscore: true,
+            max_len: None,
+            min_words: 0,
+        }
+    }
+
+    /// `_private`. Allowed by default.
+    pub const fn allow_leading_underscore(mut self, allow: bool) -> Self {
+        self.leading_underscore = allow;
+        self
+    }
+
+    /// `http__server`. Allowed by default.
+    pub const fn allow_consecutive_underscores(mut self, allow: bool) -> Self {
+        self.consecutive_underscores = allow;
+        self
+    }
+
+    /// `type_`. Allowed by default.
+    pub const fn allow_trailing_underscore(mut self, allow: bool) -> Self {
+        self.trailing_underscore = allow;
+        self
+    }
+
+    /// The maximum length in bytes. No limit by default.
+    pub const fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// The minimum number of words, as counted by [`crate::SnakeStr::words`]. Zero by default.
+    pub const fn min_words(mut self, min_words: usize) -> Self {
+        self.min_words = min_words;
+        self
+    }
+
+    /// Is this snake_case, and does it follow the policy?
+    ///
+    /// The first problem found is returned.
+    pub fn validate(&self, s: &str) -> Result<(), PolicyViolation> {
+        validate(s)?;
+        let bytes = s.as_bytes();
+        if !self.leading_underscore && bytes[0] == b'_' {
+            return Err(PolicyViolation::LeadingUnderscore);
+        }
+        if !self.consecutive_underscores {
+            if let Some(offset) = s.find("__") {
+                return Err(PolicyViolation::ConsecutiveUnderscores { offset });
+            }
+        }
+        if !self.trailing_underscore && bytes[bytes.len() - 1] == b'_' {
+            return Err(PolicyViolation::TrailingUnderscore);
+        }
+        if let Some(max) = self.max_len {
+            if s.len() > max {
+                return Err(PolicyViolation::TooLong { len: s.len(), max });
+            }
+        }
+        let words = s.split('_').filter(|word| !word.is_empty()).count();
+        if words < self.min_words {
+            return Err(PolicyViolation::TooFewWords {
+                words,
+                min: self.min_words,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn is_valid(&self, s: &str) -> bool {
+        self.validate(s).is_ok()
+    }
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Why a string does not follow a [`Policy`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PolicyViolation {
+    NotSnakeCase(InvalidSnakeCase),
+    LeadingUnderscore,
+    ConsecutiveUnderscores { offset: usize },
+    TrailingUnderscore,
+    TooLong { len: usize, max: usize },
+    TooFewWords { words: usize, min: usize },
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotSnakeCase(err) => err.fmt(f),
+            Self::LeadingUnderscore => write!(f, "leading underscores are not allowed"),
+            Self::ConsecutiveUnderscores { offset } => write!(
+                f,
+                "consecutive underscores at offset {} are not allowed",
+                offset
+            ),
+            Self::TrailingUnderscore => write!(f, "trailing underscores are not allowed"),
+            Self::TooLong { len, max } => {
+                write!(
+                    f,
+                    "too long: {} bytes, but at most {} are allowed",
+                    len, max
+                )
+            }
+            Self::TooFewWords { words, min } => write!(
+                f,
+                "too few words: {}, but at least {} are required",
+                words, min
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PolicyViolation {}
+
+impl From<InvalidSnakeCase> for PolicyViolation {
+    fn from(err: InvalidSnakeCase) -> Self {
+        Self::NotSnakeCase(err)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Names a [`Policy`] at the type level, for [`PolicySnakeCase`].
+///
+/// ```
+/// # use snake_case::{HasPolicy, Policy, PolicySnakeCase};
+/// struct StyleGuide;
+///
+/// impl HasPolicy for StyleGuide {
+///     const POLICY: Policy = Policy::new().allow_trailing_underscore(false);
+/// }
+///
+/// type ColumnName = PolicySnakeCase<StyleGuide>;
+///
+/// assert!(ColumnName::try_from_str("user_id").is_ok());
+/// assert!(ColumnName::try_from_str("type_").is_err());
+/// ```
+pub trait HasPolicy {
+    const POLICY: Policy;
+}
+
+/// A [`SnakeCase`] that also follows the [`Policy`] of `P`.
+///
+/// Like [`SnakeCase`] it is checked on construction (and deserialization), so it can be trusted afterwards.
+#[cfg(feature = "alloc")]
+pub struct PolicySnakeCase<P> {
+    snake_case: SnakeCase,
+    policy: PhantomData<fn() -> P>,
+}
+
+#[cfg(feature = "alloc")]
+impl<P: HasPolicy> PolicySnakeCase<P> {
+    pub fn try_from_str(s: &str) -> Result<Self, PolicyViolation> {
+        P::POLICY.validate(s)?;
+        Ok(Self::new_unchecked(SnakeCase::from_string_unchecked(
+            s.into(),
+        )))
+    }
+
+    /// Returns the snake_case back, together with the violation, if it doesn't follow the policy.
+    pub fn try_from_snake_case(
+        snake_case: SnakeCase,
+    ) -> Result<Self, (SnakeCase, PolicyViolation)> {
+        match P::POLICY.validate(snake_case.as_str()) {
+            Ok(()) => Ok(Self::new_unchecked(snake_case)),
+            Err(err) => Err((snake_case, err)),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> PolicySnakeCase<P> {
+    fn new_unchecked(snake_case: SnakeCase) -> Self {
+        Self {
+            snake_case,
+            policy: PhantomData,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.snake_case.as_str()
+    }
+
+    pub fn as_snake_case(&self) -> &SnakeCase {
+        &self.snake_case
+    }
+
+    pub fn into_snake_case(self) -> SnakeCase {
+        self.snake_case
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Clone for PolicySnakeCase<P> {
+    fn clone(&self) -> Self {
+        Self::new_unchecked(self.snake_case.clone())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> PartialEq for PolicySnakeCase<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.snake_case == other.snake_case
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Eq for PolicySnakeCase<P> {}
+
+#[cfg(feature = "alloc")]
+impl<P> PartialOrd for PolicySnakeCase<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Ord for PolicySnakeCase<P> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.snake_case.cmp(&other.snake_case)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> core::hash::Hash for PolicySnakeCase<P> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.snake_case.hash(state);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> PartialEq<str> for PolicySnakeCase<P> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> PartialEq<&str> for PolicySnakeCase<P> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Deref for PolicySnakeCase<P> {
+    type Target = SnakeStr;
+
+    fn deref(&self) -> &SnakeStr {
+        &self.snake_case
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> AsRef<str> for PolicySnakeCase<P> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> From<PolicySnakeCase<P>> for SnakeCase {
+    fn from(snake_case: PolicySnakeCase<P>) -> Self {
+        snake_case.snake_case
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: HasPolicy> TryFrom<&str> for PolicySnakeCase<P> {
+    type Error = PolicyViolation;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: HasPolicy> FromStr for PolicySnakeCase<P> {
+    type Err = PolicyViolation;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> fmt::Debug for PolicySnakeCase<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> fmt::Display for PolicySnakeCase<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "serde"))]
+impl<P> serde::Serialize for PolicySnakeCase<P> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "serde"))]
+impl<'de, P: HasPolicy> serde::Deserialize<'de> for PolicySnakeCase<P> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let snake_case = SnakeCase::deserialize(deserializer)?;
+        Self::try_from_snake_case(snake_case).map_err(|(_, err)| serde::de::Error::custom(err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString as _;
+
+    use super::*;
+
+    #[test]
+    fn policy() {
+        let policy = Policy::new().allow_leading_underscore(false).min_words(2);
+        assert!(policy.is_valid("a_b"));
+        assert!(policy.is_valid("a__b_"));
+        assert_eq!(
+            policy.validate("_a_b"),
+            Err(PolicyViolation::LeadingUnderscore)
+        );
+        assert_eq!(
+            policy.validate("a_"),
+            Err(PolicyViolation::TooFewWords { words: 1, min: 2 })
+        );
+        assert_eq!(
+            policy.validate("A_b"),
+            Err(PolicyViolation::NotSnakeCase(
+                InvalidSnakeCase::UppercaseChar { offset: 0 }
+            ))
+        );
+        assert_eq!(
+            Policy::new().max_len(3).validate("abcd"),
+            Err(PolicyViolation::TooLong { len: 4, max: 3 })
+        );
+        assert!(Policy::default().is_valid("_"));
+    }
+
+    struct ShortNames;
+
+    impl HasPolicy for ShortNames {
+        const POLICY: Policy = Policy::new().min_words(1).max_len(8);
+    }
+
+    #[test]
+    fn policy_snake_case() {
+        let name: PolicySnakeCase<ShortNames> = "user_id".parse().unwrap();
+        assert_eq!(name, "user_id");
+        assert!(name.starts_with(SnakeStr::try_from_str("user").unwrap()));
+        assert!("___".parse::<PolicySnakeCase<ShortNames>>().is_err());
+
+        let (snake_case, err) = PolicySnakeCase::<ShortNames>::try_from_snake_case(
+            SnakeCase::try_from_str("very_long_name").unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(snake_case, "very_long_name");
+        assert_eq!(
+            err.to_string(),
+            "too long: 14 bytes, but at most 8 are allowed"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let name: PolicySnakeCase<ShortNames> = serde_json::from_str(r#""user_id""#).unwrap();
+        assert_eq!(serde_json::to_string(&name).unwrap(), r#""user_id""#);
+        assert!(serde_json::from_str::<PolicySnakeCase<ShortNames>>(r#""_""#).is_err());
+    }
+}
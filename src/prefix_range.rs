@@ -0,0 +1,95 @@
+use alloc::string::String;
+use core::ops::{Bound, RangeBounds};
+
+use crate::SnakeStr;
+
+/// The range of keys that [`SnakeStr::starts_with`] a prefix, not counting the prefix itself.
+///
+/// See [`SnakeStr::prefix_range`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnakeCasePrefix {
+    /// The prefix with a trailing underscore.
+    start: String,
+
+    /// Was the trailing underscore already part of the prefix?
+    exclude_start: bool,
+
+    /// `start` with the trailing underscore replaced by the next character, '`'.
+    end: String,
+}
+
+impl RangeBounds<str> for SnakeCasePrefix {
+    fn start_bound(&self) -> Bound<&str> {
+        if self.exclude_start {
+            Bound::Excluded(&self.start)
+        } else {
+            Bound::Included(&self.start)
+        }
+    }
+
+    fn end_bound(&self) -> Bound<&str> {
+        Bound::Excluded(&self.end)
+    }
+}
+
+impl SnakeStr {
+    /// For range-scanning a `BTreeMap<SnakeCase, V>` (or `BTreeSet`) for everything under this prefix.
+    ///
+    /// ```
+    /// # use snake_case::{SnakeCase, SnakeStr};
+    /// # use std::collections::BTreeMap;
+    /// let mut config = BTreeMap::new();
+    /// for key in ["app", "app_db_host", "app_db_port", "app_dbx", "app_name"] {
+    ///     config.insert(SnakeCase::try_from_str(key).unwrap(), ());
+    /// }
+    ///
+    /// let prefix = SnakeStr::try_from_str("app_db").unwrap();
+    /// let keys: Vec<_> = config.range(prefix.prefix_range()).map(|(key, _)| key).collect();
+    /// assert_eq!(keys, ["app_db_host", "app_db_port"]);
+    /// ```
+    ///
+    /// This respects word boundaries, so `app_dbx` is not under `app_db`.
+    /// The prefix itself is not part of the range: look that one up separately.
+    pub fn prefix_range(&self) -> SnakeCasePrefix {
+        let mut start = String::from(self.as_str());
+        let exclude_start = start.ends_with('_');
+        if !exclude_start {
+            start.push('_');
+        }
+        let mut end = String::from(&start[..start.len() - 1]);
+        end.push('`'); // The character after '_'
+        SnakeCasePrefix {
+            start,
+            exclude_start,
+            end,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeSet, vec::Vec};
+
+    use crate::SnakeCase;
+
+    #[test]
+    fn same_as_starts_with() {
+        let keys = [
+            "a", "a_", "a__b", "a_2", "a_b", "a_b_c", "a_z", "a0", "a9_b", "aa", "b", "b_a", "_a",
+        ];
+        let set: BTreeSet<SnakeCase> = keys
+            .iter()
+            .map(|key| SnakeCase::try_from_str(key).unwrap())
+            .collect();
+
+        for prefix in ["a", "a_", "a_b", "b", "_", "z"] {
+            let prefix = SnakeCase::try_from_str(prefix).unwrap();
+            let in_range: Vec<&SnakeCase> = set.range(prefix.prefix_range()).collect();
+            let expected: Vec<&SnakeCase> = set
+                .iter()
+                .filter(|key| key.starts_with(&*prefix) && **key != prefix)
+                .collect();
+            assert_eq!(in_range, expected, "prefix {}", prefix);
+        }
+    }
+}
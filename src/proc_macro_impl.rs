@@ -0,0 +1,60 @@
+use core::convert::TryFrom;
+
+use proc_macro2::{Ident, Span};
+
+use crate::{InvalidSnakeCase, SnakeCase, SnakeStr};
+
+impl SnakeStr {
+    /// The same identifier as [`Self::to_rust_ident`], so keywords come out as raw identifiers
+    /// (`r#type`) and `self`, `super`, `crate` and `_` get a trailing underscore.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCaseRef;
+    /// # use proc_macro2::Span;
+    /// let field = SnakeCaseRef::try_from_str("type").unwrap();
+    /// assert_eq!(field.to_ident(Span::call_site()).to_string(), "r#type");
+    /// ```
+    pub fn to_ident(&self, span: Span) -> Ident {
+        let ident = self.to_rust_ident();
+        match ident.strip_prefix("r#") {
+            Some(raw) => Ident::new_raw(raw, span),
+            None => Ident::new(&ident, span),
+        }
+    }
+}
+
+/// Raw identifiers are accepted without their `r#` prefix, so `r#type` becomes `type`.
+///
+/// `syn::Ident` is the same type, so this works for those too.
+impl TryFrom<&Ident> for SnakeCase {
+    type Error = InvalidSnakeCase;
+
+    fn try_from(ident: &Ident) -> Result<Self, Self::Error> {
+        let string = ident.to_string();
+        match string.strip_prefix("r#") {
+            Some(raw) => SnakeCase::try_from_str(raw),
+            None => SnakeCase::try_from_string(string),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for name in ["user_id", "type", "async", "self", "_"] {
+            let snake = SnakeCase::try_from_str(name).unwrap();
+            let ident = snake.to_ident(Span::call_site());
+            let back = SnakeCase::try_from(&ident).unwrap();
+            assert_eq!(
+                back.as_str(),
+                snake.to_rust_ident().trim_start_matches("r#")
+            );
+        }
+
+        let ident = Ident::new("UserId", Span::call_site());
+        assert!(SnakeCase::try_from(&ident).is_err());
+    }
+}
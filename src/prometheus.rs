@@ -0,0 +1,320 @@
+use alloc::string::String;
+use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
+
+use crate::{validate, Convention, ConventionError, InvalidSnakeCase, SnakeCase, SnakeCaseRef};
+
+/// A valid Prometheus metric name, matching `^[a-zA-Z_:][a-zA-Z0-9_:]*$`.
+///
+/// Every snake_case string is one, so converting from [`SnakeCase`] can't fail:
+///
+/// ```
+/// # use snake_case::{MetricName, SnakeCase};
+/// let name = MetricName::from(SnakeCase::try_from_str("http_requests_total").unwrap());
+/// assert_eq!(name, "http_requests_total");
+///
+/// assert!(MetricName::try_from_str("job:http_requests:rate5m").is_ok());
+/// assert!(MetricName::try_from_str("http-requests").is_err());
+/// ```
+#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MetricName(String);
+
+impl MetricName {
+    pub fn try_from_str(s: &str) -> Result<Self, ConventionError> {
+        Self::try_from_string(s.into())
+    }
+
+    pub fn try_from_string(s: String) -> Result<Self, ConventionError> {
+        Convention::PROMETHEUS_METRIC.validate(&s)?;
+        Ok(Self(s))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl From<SnakeCase> for MetricName {
+    fn from(snake: SnakeCase) -> Self {
+        Self(snake.into_string())
+    }
+}
+
+impl From<SnakeCaseRef<'_>> for MetricName {
+    fn from(snake: SnakeCaseRef<'_>) -> Self {
+        Self(snake.as_str().into())
+    }
+}
+
+impl From<LabelKey> for MetricName {
+    fn from(key: LabelKey) -> Self {
+        Self::from(key.0)
+    }
+}
+
+impl TryFrom<&str> for MetricName {
+    type Error = ConventionError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(s)
+    }
+}
+
+impl TryFrom<String> for MetricName {
+    type Error = ConventionError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from_string(s)
+    }
+}
+
+impl FromStr for MetricName {
+    type Err = ConventionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl Deref for MetricName {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for MetricName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for MetricName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for MetricName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl PartialEq<str> for MetricName {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for MetricName {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A Prometheus label name that is also snake_case.
+///
+/// Prometheus reserves label names starting with `__` for internal use, so those are rejected.
+///
+/// ```
+/// # use snake_case::{InvalidLabelKey, LabelKey};
+/// assert_eq!(LabelKey::try_from_str("status_code").unwrap(), "status_code");
+/// assert_eq!(LabelKey::try_from_str("__name__"), Err(InvalidLabelKey::Reserved));
+/// ```
+#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LabelKey(SnakeCase);
+
+/// Why a string is not a [`LabelKey`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvalidLabelKey {
+    NotSnakeCase(InvalidSnakeCase),
+
+    /// Starts with `__`, which Prometheus reserves.
+    Reserved,
+}
+
+impl fmt::Display for InvalidLabelKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotSnakeCase(err) => err.fmt(f),
+            Self::Reserved => write!(f, "label names starting with `__` are reserved"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidLabelKey {}
+
+impl From<InvalidSnakeCase> for InvalidLabelKey {
+    fn from(err: InvalidSnakeCase) -> Self {
+        Self::NotSnakeCase(err)
+    }
+}
+
+impl LabelKey {
+    pub fn try_from_str(s: &str) -> Result<Self, InvalidLabelKey> {
+        validate(s)?;
+        Self::try_from_snake_case(SnakeCase::from_string_unchecked(s.into()))
+    }
+
+    pub fn try_from_snake_case(snake: SnakeCase) -> Result<Self, InvalidLabelKey> {
+        if snake.as_str().starts_with("__") {
+            Err(InvalidLabelKey::Reserved)
+        } else {
+            Ok(Self(snake))
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn as_snake_case(&self) -> &SnakeCase {
+        &self.0
+    }
+
+    pub fn into_snake_case(self) -> SnakeCase {
+        self.0
+    }
+}
+
+impl TryFrom<SnakeCase> for LabelKey {
+    type Error = InvalidLabelKey;
+
+    fn try_from(snake: SnakeCase) -> Result<Self, Self::Error> {
+        Self::try_from_snake_case(snake)
+    }
+}
+
+impl TryFrom<&str> for LabelKey {
+    type Error = InvalidLabelKey;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(s)
+    }
+}
+
+impl FromStr for LabelKey {
+    type Err = InvalidLabelKey;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl From<LabelKey> for SnakeCase {
+    fn from(key: LabelKey) -> Self {
+        key.0
+    }
+}
+
+impl Deref for LabelKey {
+    type Target = crate::SnakeStr;
+
+    fn deref(&self) -> &crate::SnakeStr {
+        &self.0
+    }
+}
+
+impl AsRef<str> for LabelKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for LabelKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for LabelKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl PartialEq<str> for LabelKey {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for LabelKey {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MetricName {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MetricName {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error as _;
+        let string = String::deserialize(deserializer)?;
+        Self::try_from_string(string).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for LabelKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LabelKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error as _;
+        let snake = SnakeCase::deserialize(deserializer)?;
+        Self::try_from_snake_case(snake).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prometheus() {
+        assert!(MetricName::try_from_str(":colon_first").is_ok());
+        assert!(MetricName::try_from_str("CamelCase").is_ok());
+        assert!(MetricName::try_from_str("2xx").is_err());
+        assert!(MetricName::try_from_str("").is_err());
+
+        let key = LabelKey::try_from_str("_method").unwrap();
+        assert_eq!(MetricName::from(key), "_method");
+        assert_eq!(
+            LabelKey::try_from_str("Method"),
+            Err(InvalidLabelKey::NotSnakeCase(
+                InvalidSnakeCase::UppercaseChar { offset: 0 }
+            ))
+        );
+        assert_eq!(
+            LabelKey::try_from(SnakeCase::try_from_str("__meta").unwrap()),
+            Err(InvalidLabelKey::Reserved)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let name: MetricName = serde_json::from_str(r#""up:sum""#).unwrap();
+        assert_eq!(serde_json::to_string(&name).unwrap(), r#""up:sum""#);
+        assert!(serde_json::from_str::<LabelKey>(r#""__name__""#).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+use std::convert::{Infallible, TryFrom as _};
+
+use pyo3::{
+    exceptions::PyValueError, types::PyString, Borrowed, Bound, FromPyObject, IntoPyObject, PyAny,
+    PyErr, Python,
+};
+
+use crate::{SnakeCase, SnakeCaseRef};
+
+/// Accepts a Python `str`, raising `ValueError` (with the offset of the offending character)
+/// if it isn't snake_case.
+impl FromPyObject<'_, '_> for SnakeCase {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, '_, PyAny>) -> Result<Self, PyErr> {
+        let string: String = obj.extract()?;
+        Self::try_from(string).map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+}
+
+macro_rules! impl_into_py_object {
+    ($($ty:ty),*) => {
+        $(
+            impl<'py> IntoPyObject<'py> for $ty {
+                type Target = PyString;
+                type Output = Bound<'py, PyString>;
+                type Error = Infallible;
+
+                fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Infallible> {
+                    Ok(PyString::new(py, self.as_str()))
+                }
+            }
+        )*
+    };
+}
+
+impl_into_py_object!(SnakeCase, &SnakeCase, SnakeCaseRef<'_>);
+
+#[cfg(test)]
+mod tests {
+    use pyo3::types::PyAnyMethods as _;
+
+    use super::*;
+
+    #[test]
+    fn pyo3() {
+        Python::initialize();
+        Python::attach(|py| {
+            let snake = SnakeCase::try_from_str("user_id").unwrap();
+            let object = (&snake).into_pyobject(py).unwrap();
+            assert_eq!(object.to_string(), "user_id");
+            assert_eq!(object.extract::<SnakeCase>().unwrap(), snake);
+
+            let err = PyString::new(py, "userId")
+                .extract::<SnakeCase>()
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+            assert_eq!(
+                err.value(py).to_string(),
+                "upper case character at offset 4: snake_case must be lower case"
+            );
+
+            assert!(42_i32
+                .into_pyobject(py)
+                .unwrap()
+                .extract::<SnakeCase>()
+                .is_err());
+        });
+    }
+}
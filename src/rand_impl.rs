@@ -0,0 +1,125 @@
+use alloc::string::String;
+use core::fmt::Write as _;
+
+use rand::{
+    distr::{Distribution, StandardUniform},
+    seq::IndexedRandom as _,
+    Rng, RngExt as _,
+};
+
+use crate::{SnakeCase, SnakeCaseRef, SnakeStr};
+
+macro_rules! words {
+    ($($word:literal),* $(,)?) => {
+        &[$(SnakeCaseRef::from_str_or_panic($word)),*]
+    };
+}
+
+const ADJECTIVES: &[SnakeCaseRef<'static>] = words![
+    "brave", "calm", "clever", "eager", "fancy", "gentle", "happy", "jolly", "kind", "lively",
+    "lucky", "proud", "quiet", "shiny", "swift", "witty",
+];
+
+const ANIMALS: &[SnakeCaseRef<'static>] = words![
+    "badger", "beaver", "crane", "falcon", "gecko", "heron", "koala", "lemur", "lynx", "otter",
+    "panda", "puffin", "raven", "tapir", "walrus", "yak",
+];
+
+impl SnakeCase {
+    /// A random string of `len` lower case ASCII letters and digits, starting with a letter.
+    ///
+    /// # Panics
+    /// If `len` is zero.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R, len: usize) -> SnakeCase {
+        const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+        const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+        assert!(len > 0, "snake_case can't be empty");
+        let mut string = String::with_capacity(len);
+        string.push(*LETTERS.choose(rng).unwrap() as char);
+        for _ in 1..len {
+            string.push(*CHARS.choose(rng).unwrap() as char);
+        }
+        SnakeCase::from_string_unchecked(string)
+    }
+
+    /// Picks one random word from each list and joins them with underscores.
+    ///
+    /// ```
+    /// # use snake_case::{SnakeCase, SnakeCaseRef};
+    /// let colors = [SnakeCaseRef::from_str_or_panic("red"), SnakeCaseRef::from_str_or_panic("green")];
+    /// let fruits = [SnakeCaseRef::from_str_or_panic("apple"), SnakeCaseRef::from_str_or_panic("pear")];
+    /// let lists = [&colors[..], &fruits[..]];
+    /// let id = SnakeCase::random_words(&mut rand::rng(), &lists).unwrap();
+    /// assert_eq!(id.word_count(), 2);
+    /// ```
+    ///
+    /// Returns `None` if there are no lists, or any of them is empty.
+    pub fn random_words<R: Rng + ?Sized, W: AsRef<SnakeStr>>(
+        rng: &mut R,
+        word_lists: &[&[W]],
+    ) -> Option<SnakeCase> {
+        let mut string = String::new();
+        for list in word_lists {
+            if !string.is_empty() {
+                string.push('_');
+            }
+            string.push_str(list.choose(rng)?.as_ref().as_str());
+        }
+        // Valid snake_case joined with underscores is valid snake_case.
+        if string.is_empty() {
+            None
+        } else {
+            Some(SnakeCase::from_string_unchecked(string))
+        }
+    }
+}
+
+/// Human-readable identifiers like `brave_otter_3f09a1c2`, handy for tests.
+///
+/// The random 32-bit hex suffix makes collisions unlikely until you have drawn around a million.
+impl Distribution<SnakeCase> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SnakeCase {
+        let mut string = SnakeCase::random_words(rng, &[ADJECTIVES, ANIMALS])
+            .expect("non-empty word lists")
+            .into_string();
+        write!(string, "_{:08x}", rng.random::<u32>()).ok();
+        SnakeCase::from_string_unchecked(string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+
+    use rand::{rngs::StdRng, SeedableRng as _};
+
+    use super::*;
+
+    #[test]
+    fn random() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for len in 1..20 {
+            let snake = SnakeCase::random(&mut rng, len);
+            assert_eq!(snake.as_str().len(), len);
+            assert!(crate::is_snake_case(snake.as_str()));
+        }
+
+        let snake: SnakeCase = rng.random();
+        assert!(crate::is_snake_case(snake.as_str()));
+        assert_eq!(snake.word_count(), 3);
+
+        let ids: BTreeSet<SnakeCase> = (0..5_000).map(|_| rng.random()).collect();
+        assert_eq!(ids.len(), 5_000);
+
+        let empty: &[SnakeCaseRef<'_>] = &[];
+        assert_eq!(
+            SnakeCase::random_words(&mut rng, &[ADJECTIVES, empty]),
+            None
+        );
+        assert_eq!(
+            SnakeCase::random_words::<_, SnakeCaseRef<'_>>(&mut rng, &[]),
+            None
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use rayon::prelude::*;
+
+use crate::{swar::validate_fast, BatchError, SnakeCase};
+
+impl SnakeCase {
+    /// Validate and convert many strings in parallel, reporting every invalid one.
+    ///
+    /// Owned `String`s are reused, `&str`s are copied.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCase;
+    /// let columns = vec!["id".to_owned(), "user_name".to_owned()];
+    /// let columns = SnakeCase::try_from_par_iter(columns).unwrap();
+    /// assert_eq!(columns[1], "user_name");
+    /// ```
+    pub fn try_from_par_iter<I>(strings: I) -> Result<Vec<SnakeCase>, BatchError>
+    where
+        I: IntoParallelIterator,
+        I::Iter: IndexedParallelIterator,
+        I::Item: AsRef<str> + Into<String>,
+    {
+        let results: Vec<_> = strings
+            .into_par_iter()
+            .map(|s| match validate_fast(s.as_ref()) {
+                Ok(()) => Ok(SnakeCase::from_string_unchecked(s.into())),
+                Err(err) => Err(err),
+            })
+            .collect();
+
+        if results.iter().all(Result::is_ok) {
+            Ok(results.into_iter().flatten().collect())
+        } else {
+            let invalid = results
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, result)| result.err().map(|err| (index, err)))
+                .collect();
+            Err(BatchError { invalid })
+        }
+    }
+}
+
+/// Like [`crate::validate_all`], but in parallel.
+pub fn validate_par<'a, I>(strings: I) -> Result<(), BatchError>
+where
+    I: IntoParallelIterator<Item = &'a str>,
+    I::Iter: IndexedParallelIterator,
+{
+    let invalid: Vec<_> = strings
+        .into_par_iter()
+        .enumerate()
+        .filter_map(|(index, s)| validate_fast(s).err().map(|err| (index, err)))
+        .collect();
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(BatchError { invalid })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InvalidSnakeCase;
+
+    #[test]
+    fn parallel() {
+        let strings: Vec<String> = (0..1000).map(|i| format!("column_{}", i)).collect();
+        let snakes = SnakeCase::try_from_par_iter(strings.clone()).unwrap();
+        assert_eq!(snakes.len(), 1000);
+        assert_eq!(snakes[999], "column_999");
+
+        let mut strs: Vec<&str> = strings.iter().map(|s| s.as_str()).collect();
+        assert!(validate_par(strs.clone()).is_ok());
+        strs[500] = "Column";
+        strs[700] = "";
+        let err = validate_par(strs.clone()).unwrap_err();
+        assert_eq!(
+            err.invalid,
+            [
+                (500, InvalidSnakeCase::UppercaseChar { offset: 0 }),
+                (700, InvalidSnakeCase::Empty)
+            ]
+        );
+        assert_eq!(SnakeCase::try_from_par_iter(strs).unwrap_err(), err);
+    }
+}
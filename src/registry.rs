@@ -0,0 +1,158 @@
+//! A process-wide registry of unique names, like event or metric names.
+//!
+//! Each name can be registered once. Registering it again (say, from another crate) is an error,
+//! so clashes are caught at startup instead of silently mixing up two things with the same name.
+//!
+//! ```
+//! use snake_case::{registry, SnakeCase};
+//!
+//! let clicked = registry::register(SnakeCase::try_from_str("button_clicked").unwrap()).unwrap();
+//! assert_eq!(clicked, "button_clicked");
+//!
+//! let again = registry::register(SnakeCase::try_from_str("button_clicked").unwrap());
+//! assert!(again.is_err());
+//!
+//! assert_eq!(registry::get("button_clicked"), Some(clicked));
+//! assert!(registry::names().contains(&clicked));
+//! ```
+//!
+//! Registered names are leaked (see [`SnakeCase::leak`]), so they live for the rest of the process
+//! and [`Registered`] is a cheap `Copy` handle.
+
+use std::{collections::BTreeSet, fmt, ops::Deref, sync::Mutex, vec::Vec};
+
+use crate::{SnakeCase, SnakeCaseRef, SnakeStr};
+
+static NAMES: Mutex<BTreeSet<SnakeCaseRef<'static>>> = Mutex::new(BTreeSet::new());
+
+fn names_lock() -> std::sync::MutexGuard<'static, BTreeSet<SnakeCaseRef<'static>>> {
+    // A panic while holding the lock can't leave the set half-modified, so ignore poisoning:
+    NAMES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Register a name, failing if it already is.
+pub fn register(name: SnakeCase) -> Result<Registered, DuplicateName> {
+    let mut names = names_lock();
+    if names.contains(name.as_str()) {
+        return Err(DuplicateName { name });
+    }
+    let name = name.leak();
+    names.insert(name);
+    Ok(Registered(name))
+}
+
+/// Look up a registered name.
+pub fn get(name: &str) -> Option<Registered> {
+    names_lock().get(name).copied().map(Registered)
+}
+
+/// All registered names, in sorted order.
+///
+/// This is a snapshot: names registered later are not included.
+pub fn names() -> Vec<Registered> {
+    names_lock().iter().copied().map(Registered).collect()
+}
+
+/// A name in the [registry](self), returned by [`register`].
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Registered(SnakeCaseRef<'static>);
+
+impl Registered {
+    pub fn as_str(&self) -> &'static str {
+        self.0.as_str()
+    }
+
+    pub fn as_snake_case_ref(&self) -> SnakeCaseRef<'static> {
+        self.0
+    }
+}
+
+impl From<Registered> for SnakeCaseRef<'static> {
+    fn from(registered: Registered) -> Self {
+        registered.0
+    }
+}
+
+impl Deref for Registered {
+    type Target = SnakeStr;
+
+    fn deref(&self) -> &SnakeStr {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Registered {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<SnakeStr> for Registered {
+    fn as_ref(&self) -> &SnakeStr {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Registered {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for Registered {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl PartialEq<str> for Registered {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Registered {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+/// The error from [`register`]ing a name that already is.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DuplicateName {
+    /// The name that was passed to [`register`].
+    pub name: SnakeCase,
+}
+
+impl fmt::Display for DuplicateName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is already registered", self.name)
+    }
+}
+
+impl std::error::Error for DuplicateName {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry() {
+        // The registry is shared by all tests, so use names nothing else does:
+        let snake = |s| SnakeCase::try_from_str(s).unwrap();
+        let a = register(snake("registry_test_a")).unwrap();
+        let b = register(snake("registry_test_b")).unwrap();
+        let err = register(snake("registry_test_a")).unwrap_err();
+        assert_eq!(err.to_string(), "`registry_test_a` is already registered");
+
+        assert_eq!(get("registry_test_b"), Some(b));
+        assert_eq!(get("registry_test_c"), None);
+        assert_eq!(get("Not snake"), None);
+
+        let names = names();
+        let a_pos = names.iter().position(|n| *n == a).unwrap();
+        assert_eq!(names[a_pos + 1], b);
+    }
+}
@@ -0,0 +1,163 @@
+use alloc::string::String;
+
+use crate::{rust_ident, sql_ident, SnakeCase, SnakeStr, SqlDialect};
+
+/// The words a target language reserves, for code generators that emit identifiers in several languages.
+///
+/// Only the snake_case words are included, since nothing else can conflict with a [`SnakeStr`]
+/// (so not Python's `None`, for instance).
+///
+/// ```
+/// # use snake_case::{ReservedWords, SnakeCaseRef, SqlDialect};
+/// let name = SnakeCaseRef::try_from_str("class").unwrap();
+/// assert!(name.conflicts_with(ReservedWords::PYTHON));
+/// assert!(!name.conflicts_with(ReservedWords::RUST));
+/// assert_eq!(name.escape_for(ReservedWords::PYTHON), "class_");
+///
+/// let column = SnakeCaseRef::try_from_str("select").unwrap();
+/// assert!(column.conflicts_with(ReservedWords::sql(SqlDialect::Postgres)));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ReservedWords {
+    /// Sorted, for binary search.
+    words: &'static [&'static str],
+}
+
+impl ReservedWords {
+    /// Strict and reserved keywords in any edition, plus `_`, like [`SnakeStr::is_rust_keyword`].
+    pub const RUST: Self = Self {
+        words: rust_ident::KEYWORDS,
+    };
+
+    /// The hard keywords of Python 3, leaving out the soft ones (`match`, `case`, `type` and `_`),
+    /// which can still be used as names.
+    pub const PYTHON: Self = Self { words: PYTHON };
+
+    /// The reserved words of JavaScript, including the ones only reserved in strict mode.
+    pub const JAVASCRIPT: Self = Self { words: JAVASCRIPT };
+
+    /// The reserved words of a SQL dialect, like [`SqlDialect::is_reserved`].
+    pub const fn sql(dialect: SqlDialect) -> Self {
+        let words = match dialect {
+            SqlDialect::Postgres => sql_ident::POSTGRES,
+            SqlDialect::MySql => sql_ident::MYSQL,
+            SqlDialect::Sqlite => sql_ident::SQLITE,
+        };
+        Self { words }
+    }
+
+    pub fn contains(self, word: &str) -> bool {
+        self.words.binary_search(&word).is_ok()
+    }
+
+    /// All the words, in sorted order.
+    pub fn words(self) -> &'static [&'static str] {
+        self.words
+    }
+}
+
+impl SnakeStr {
+    /// Is this a reserved word in the given language?
+    pub fn conflicts_with(&self, reserved: ReservedWords) -> bool {
+        reserved.contains(self.as_str())
+    }
+
+    /// Append underscores until this no longer [conflicts](Self::conflicts_with),
+    /// which is how PEP 8 and the Rust API guidelines suggest avoiding keywords (`class_`, `type_`).
+    ///
+    /// Unlike [`Self::to_rust_ident`] and [`Self::to_sql_ident`], the result is still snake_case,
+    /// and the same escape works in every language.
+    pub fn escape_for(&self, reserved: ReservedWords) -> SnakeCase {
+        let mut string = String::from(self.as_str());
+        while reserved.contains(&string) {
+            string.push('_');
+        }
+        SnakeCase::from_string_unchecked(string)
+    }
+}
+
+const PYTHON: &[&str] = &[
+    "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif",
+    "else", "except", "finally", "for", "from", "global", "if", "import", "in", "is", "lambda",
+    "nonlocal", "not", "or", "pass", "raise", "return", "try", "while", "with", "yield",
+];
+
+const JAVASCRIPT: &[&str] = &[
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_lists() {
+        for words in [PYTHON, JAVASCRIPT] {
+            assert!(words.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(words.iter().all(|word| crate::is_snake_case(word)));
+        }
+    }
+
+    #[test]
+    fn reserved_words() {
+        let snake = |s| SnakeStr::try_from_str(s).unwrap();
+        assert!(snake("function").conflicts_with(ReservedWords::JAVASCRIPT));
+        assert!(!snake("function").conflicts_with(ReservedWords::PYTHON));
+        assert!(snake("type").conflicts_with(ReservedWords::RUST));
+        assert!(!snake("type").conflicts_with(ReservedWords::PYTHON)); // only a soft keyword
+        assert_eq!(snake("user_id").escape_for(ReservedWords::RUST), "user_id");
+        assert_eq!(snake("_").escape_for(ReservedWords::RUST), "__");
+        assert_eq!(
+            snake("order").escape_for(ReservedWords::sql(SqlDialect::MySql)),
+            "order_"
+        );
+        assert!(ReservedWords::sql(SqlDialect::Sqlite)
+            .words()
+            .contains(&"pragma"));
+    }
+}
@@ -0,0 +1,163 @@
+use core::{fmt, ops::Deref};
+
+use rkyv::{
+    bytecheck::CheckBytes,
+    rancor::{Fallible, Source},
+    string::{ArchivedString, StringResolver},
+    Archive, Deserialize, Place, Portable, Serialize, SerializeUnsized,
+};
+
+use crate::{validate, SnakeCase, SnakeCaseRef, SnakeStr};
+
+/// An archived [`SnakeCase`].
+///
+/// Checking the bytes (e.g. with `rkyv::access`) also checks that the string is valid snake_case,
+/// so you can hand out references into a memory-mapped archive without copying or re-validating.
+///
+/// ```
+/// # use snake_case::{ArchivedSnakeCase, SnakeCase};
+/// let snake = SnakeCase::try_from_str("hello_world").unwrap();
+/// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&snake).unwrap();
+/// let archived = rkyv::access::<ArchivedSnakeCase, rkyv::rancor::Error>(&bytes).unwrap();
+/// assert_eq!(archived.as_str(), "hello_world");
+/// ```
+#[repr(transparent)]
+pub struct ArchivedSnakeCase(ArchivedString);
+
+// SAFETY: `ArchivedString` is portable, and we are a transparent wrapper around it.
+unsafe impl Portable for ArchivedSnakeCase {}
+
+impl ArchivedSnakeCase {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn as_snake_str(&self) -> &SnakeStr {
+        // SAFETY: only valid snake_case is ever archived (or let through `check_bytes`).
+        unsafe { SnakeStr::from_str_unchecked(self.0.as_str()) }
+    }
+
+    pub fn as_snake_case_ref(&self) -> SnakeCaseRef<'_> {
+        SnakeCaseRef(self.0.as_str())
+    }
+}
+
+impl Deref for ArchivedSnakeCase {
+    type Target = SnakeStr;
+
+    fn deref(&self) -> &SnakeStr {
+        self.as_snake_str()
+    }
+}
+
+impl fmt::Debug for ArchivedSnakeCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for ArchivedSnakeCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl PartialEq for ArchivedSnakeCase {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for ArchivedSnakeCase {}
+
+impl PartialEq<SnakeCase> for ArchivedSnakeCase {
+    fn eq(&self, other: &SnakeCase) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl PartialEq<ArchivedSnakeCase> for SnakeCase {
+    fn eq(&self, other: &ArchivedSnakeCase) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl PartialEq<str> for ArchivedSnakeCase {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ArchivedSnakeCase {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+// SAFETY: we check the bytes of the inner `ArchivedString` before reading it as a string.
+unsafe impl<C> CheckBytes<C> for ArchivedSnakeCase
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+    ArchivedString: CheckBytes<C>,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        ArchivedString::check_bytes(value.cast::<ArchivedString>(), context)?;
+        let string = (*value).as_str();
+        validate(string).map_err(<C::Error as Source>::new)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Archive for SnakeCase {
+    type Archived = ArchivedSnakeCase;
+    type Resolver = StringResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: `ArchivedSnakeCase` is a transparent wrapper around `ArchivedString`.
+        let out = unsafe { out.cast_unchecked::<ArchivedString>() };
+        ArchivedString::resolve_from_str(self.as_str(), resolver, out);
+    }
+}
+
+impl<S> Serialize<S> for SnakeCase
+where
+    S: Fallible + ?Sized,
+    S::Error: Source,
+    str: SerializeUnsized<S>,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedString::serialize_from_str(self.as_str(), serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<SnakeCase, D> for ArchivedSnakeCase {
+    fn deserialize(&self, _: &mut D) -> Result<SnakeCase, D::Error> {
+        Ok(SnakeCase(self.as_str().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::rancor::Error;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let snake = SnakeCase::try_from_str("a_somewhat_longer_snake_case_string").unwrap();
+        let bytes = rkyv::to_bytes::<Error>(&snake).unwrap();
+        let archived = rkyv::access::<ArchivedSnakeCase, Error>(&bytes).unwrap();
+        assert_eq!(*archived, snake);
+        assert_eq!(archived.word_count(), 6);
+        assert_eq!(
+            rkyv::deserialize::<SnakeCase, Error>(archived).unwrap(),
+            snake
+        );
+
+        // Same layout as a `String`, but not valid snake_case:
+        let bytes = rkyv::to_bytes::<Error>(&String::from("Hello")).unwrap();
+        assert!(rkyv::access::<ArchivedSnakeCase, Error>(&bytes).is_err());
+    }
+}
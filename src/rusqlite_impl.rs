@@ -0,0 +1,49 @@
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use crate::{SnakeCase, SnakeCaseRef, SnakeStr};
+
+impl ToSql for SnakeStr {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.as_str()))
+    }
+}
+
+impl ToSql for SnakeCase {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.as_str()))
+    }
+}
+
+impl ToSql for SnakeCaseRef<'_> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.as_str()))
+    }
+}
+
+/// Fails with [`FromSqlError::Other`] if the column is text but not valid snake_case.
+impl FromSql for SnakeCase {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        SnakeCase::try_from_str(value.as_str()?).map_err(|err| FromSqlError::Other(Box::new(err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE things (name TEXT)", []).unwrap();
+
+        let name = SnakeCase::try_from_str("hello_world").unwrap();
+        conn.execute("INSERT INTO things (name) VALUES (?1), ('Hello')", [&name])
+            .unwrap();
+
+        let mut stmt = conn.prepare("SELECT name FROM things").unwrap();
+        let names: Vec<rusqlite::Result<SnakeCase>> =
+            stmt.query_map([], |row| row.get(0)).unwrap().collect();
+        assert_eq!(names[0].as_ref().unwrap(), &name);
+        assert!(names[1].is_err());
+    }
+}
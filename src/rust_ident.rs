@@ -0,0 +1,205 @@
+use core::{fmt, ops::Deref};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, format};
+
+use crate::{validate, InvalidSnakeCase, SnakeCaseRef, SnakeStr};
+
+/// All the snake_case strings that can't be used as a plain Rust identifier:
+/// strict and reserved keywords in any edition, plus `_`.
+pub(crate) const KEYWORDS: &[&str] = &[
+    "_", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// The keywords that can't be raw identifiers either (`r#self` is an error).
+#[cfg(feature = "alloc")]
+const NOT_RAW: &[&str] = &["_", "crate", "self", "super"];
+
+impl SnakeStr {
+    /// Is this a Rust keyword (or `_`), i.e. not usable as a plain identifier?
+    ///
+    /// Reserved keywords (like `abstract` and `gen`) count too, in every edition,
+    /// so the answer doesn't depend on the edition of the code you generate.
+    pub fn is_rust_keyword(&self) -> bool {
+        KEYWORDS.binary_search(&self.as_str()).is_ok()
+    }
+
+    /// Escape keywords as raw identifiers, so the result can always be used as a Rust identifier.
+    ///
+    /// `self`, `super`, `crate` and `_` can't be raw identifiers, so they get a trailing underscore instead.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCaseRef;
+    /// let ident = |s| SnakeCaseRef::try_from_str(s).unwrap().to_rust_ident().into_owned();
+    /// assert_eq!(ident("user_id"), "user_id");
+    /// assert_eq!(ident("type"), "r#type");
+    /// assert_eq!(ident("self"), "self_");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_rust_ident(&self) -> Cow<'_, str> {
+        if !self.is_rust_keyword() {
+            Cow::Borrowed(self.as_str())
+        } else if NOT_RAW.contains(&self.as_str()) {
+            Cow::Owned(format!("{}_", self.as_str()))
+        } else {
+            Cow::Owned(format!("r#{}", self.as_str()))
+        }
+    }
+}
+
+/// Why a string is not a [`RustIdent`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvalidRustIdent {
+    NotSnakeCase(InvalidSnakeCase),
+
+    /// A keyword, or `_`.
+    Keyword,
+}
+
+impl fmt::Display for InvalidRustIdent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotSnakeCase(err) => err.fmt(f),
+            Self::Keyword => write!(f, "a Rust keyword can't be used as an identifier"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidRustIdent {}
+
+impl From<InvalidSnakeCase> for InvalidRustIdent {
+    fn from(err: InvalidSnakeCase) -> Self {
+        Self::NotSnakeCase(err)
+    }
+}
+
+/// A snake_case string that is also a valid, non-keyword Rust identifier,
+/// so it can be used as-is in generated code.
+///
+/// ```
+/// # use snake_case::{InvalidRustIdent, RustIdent};
+/// assert_eq!(RustIdent::try_from_str("user_id").unwrap(), "user_id");
+/// assert_eq!(RustIdent::try_from_str("type"), Err(InvalidRustIdent::Keyword));
+/// ```
+#[derive(Copy, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RustIdent<'a>(SnakeCaseRef<'a>);
+
+impl<'a> RustIdent<'a> {
+    pub fn try_from_str(s: &'a str) -> Result<RustIdent<'a>, InvalidRustIdent> {
+        validate(s)?;
+        RustIdent::try_from_snake(SnakeCaseRef(s))
+    }
+
+    pub fn try_from_snake(snake: SnakeCaseRef<'a>) -> Result<RustIdent<'a>, InvalidRustIdent> {
+        if snake.is_rust_keyword() {
+            Err(InvalidRustIdent::Keyword)
+        } else {
+            Ok(RustIdent(snake))
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.0.as_str()
+    }
+
+    pub fn as_snake_case_ref(&self) -> SnakeCaseRef<'a> {
+        self.0
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a str> for RustIdent<'a> {
+    type Error = InvalidRustIdent;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        RustIdent::try_from_str(s)
+    }
+}
+
+impl<'a> From<RustIdent<'a>> for SnakeCaseRef<'a> {
+    fn from(ident: RustIdent<'a>) -> Self {
+        ident.0
+    }
+}
+
+impl Deref for RustIdent<'_> {
+    type Target = SnakeStr;
+
+    fn deref(&self) -> &SnakeStr {
+        self.0.as_snake_str()
+    }
+}
+
+impl AsRef<SnakeStr> for RustIdent<'_> {
+    fn as_ref(&self) -> &SnakeStr {
+        self
+    }
+}
+
+impl AsRef<str> for RustIdent<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for RustIdent<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for RustIdent<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl PartialEq<str> for RustIdent<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for RustIdent<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords_are_sorted() {
+        assert!(KEYWORDS.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(KEYWORDS.iter().all(|k| crate::is_snake_case(k)));
+    }
+
+    #[test]
+    fn rust_ident() {
+        let snake = |s| SnakeCaseRef::try_from_str(s).unwrap();
+        assert!(snake("type").is_rust_keyword());
+        assert!(snake("_").is_rust_keyword());
+        assert!(!snake("types").is_rust_keyword());
+        assert!(!snake("union").is_rust_keyword()); // only a contextual keyword
+        assert_eq!(snake("crate").to_rust_ident(), "crate_");
+        assert_eq!(snake("async").to_rust_ident(), "r#async");
+
+        assert_eq!(
+            RustIdent::try_from_str("Type"),
+            Err(InvalidRustIdent::NotSnakeCase(
+                InvalidSnakeCase::UppercaseChar { offset: 0 }
+            ))
+        );
+        assert_eq!(
+            RustIdent::try_from_snake(snake("loop")),
+            Err(InvalidRustIdent::Keyword)
+        );
+        assert_eq!(RustIdent::try_from_str("loops").unwrap(), "loops");
+    }
+}
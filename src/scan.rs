@@ -0,0 +1,125 @@
+use crate::SnakeCaseRef;
+
+const fn is_start_byte(b: u8) -> bool {
+    b == b'_' || b.is_ascii_lowercase()
+}
+
+const fn is_snake_case_byte(b: u8) -> bool {
+    b == b'_' || b.is_ascii_lowercase() || b.is_ascii_digit()
+}
+
+/// Split off the longest snake_case prefix of the input, returning it and the rest.
+///
+/// Returns `None` if the input doesn't start with a lower case ASCII letter or underscore.
+///
+/// ```
+/// # use snake_case::take_snake_case_prefix;
+/// let (ident, rest) = take_snake_case_prefix("max_retries = 3").unwrap();
+/// assert_eq!(ident, "max_retries");
+/// assert_eq!(rest, " = 3");
+/// assert!(take_snake_case_prefix("3 + x").is_none());
+/// ```
+pub const fn take_snake_case_prefix(input: &str) -> Option<(SnakeCaseRef<'_>, &str)> {
+    let bytes = input.as_bytes();
+    if bytes.is_empty() || !is_start_byte(bytes[0]) {
+        return None;
+    }
+    let mut end = 1;
+    while end < bytes.len() && is_snake_case_byte(bytes[end]) {
+        end += 1;
+    }
+    let (prefix, rest) = bytes.split_at(end);
+    // SAFETY: we split right before a byte that is not a snake_case byte, which is never a
+    // UTF-8 continuation byte, so both halves are valid UTF-8.
+    unsafe {
+        Some((
+            SnakeCaseRef(core::str::from_utf8_unchecked(prefix)),
+            core::str::from_utf8_unchecked(rest),
+        ))
+    }
+}
+
+/// Find every snake_case identifier in the text, with its byte offset.
+///
+/// The text is split into tokens of ASCII letters, digits, underscores and non-ASCII characters,
+/// and the tokens that are valid snake_case are returned.
+/// So `fooBar` and `straße` are skipped entirely, rather than yielding `foo` and `stra`.
+///
+/// ```
+/// # use snake_case::find_snake_case;
+/// let idents: Vec<_> = find_snake_case("let fooBar = my_var2 + 42;")
+///     .map(|(offset, ident)| (offset, ident.as_str()))
+///     .collect();
+/// assert_eq!(idents, [(0, "let"), (13, "my_var2")]);
+/// ```
+pub fn find_snake_case(haystack: &str) -> impl Iterator<Item = (usize, SnakeCaseRef<'_>)> {
+    FindSnakeCase { haystack, pos: 0 }
+}
+
+struct FindSnakeCase<'a> {
+    haystack: &'a str,
+    pos: usize,
+}
+
+impl<'a> Iterator for FindSnakeCase<'a> {
+    type Item = (usize, SnakeCaseRef<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_token_byte = |b: u8| b == b'_' || b.is_ascii_alphanumeric() || !b.is_ascii();
+        let bytes = self.haystack.as_bytes();
+        loop {
+            while self.pos < bytes.len() && !is_token_byte(bytes[self.pos]) {
+                self.pos += 1;
+            }
+            if self.pos == bytes.len() {
+                return None;
+            }
+
+            let start = self.pos;
+            while self.pos < bytes.len() && is_token_byte(bytes[self.pos]) {
+                self.pos += 1;
+            }
+
+            // Tokens start and end at ASCII bytes (or the ends of the string), i.e. char boundaries.
+            let token = &self.haystack[start..self.pos];
+            if crate::is_snake_case(token) {
+                return Some((start, SnakeCaseRef(token)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn take_prefix() {
+        let take = |s| take_snake_case_prefix(s).map(|(prefix, rest)| (prefix.as_str(), rest));
+        assert_eq!(take("foo"), Some(("foo", "")));
+        assert_eq!(take("_foo2Bar"), Some(("_foo2", "Bar")));
+        assert_eq!(take("a.b"), Some(("a", ".b")));
+        assert_eq!(take("stra\u{df}e"), Some(("stra", "\u{df}e")));
+        assert_eq!(take(""), None);
+        assert_eq!(take("2d"), None);
+        assert_eq!(take("Foo"), None);
+
+        const PREFIX: Option<(SnakeCaseRef<'static>, &str)> = take_snake_case_prefix("a b");
+        assert!(PREFIX.is_some());
+    }
+
+    #[test]
+    fn find() {
+        let find = |s| {
+            find_snake_case(s)
+                .map(|(offset, ident)| (offset, ident.as_str()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(find(""), []);
+        assert_eq!(find("fn main() {}"), [(0, "fn"), (3, "main")]);
+        assert_eq!(find("a.b_c::_d"), [(0, "a"), (2, "b_c"), (7, "_d")]);
+        assert_eq!(find("Foo 3d stra\u{df}e x"), [(15, "x")]);
+    }
+}
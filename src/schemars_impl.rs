@@ -0,0 +1,52 @@
+use alloc::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{SnakeCase, SnakeCaseRef, SnakeStr};
+
+/// All our snake_case types serialize the same way, so they share a schema.
+fn snake_case_schema() -> Schema {
+    json_schema!({
+        "type": "string",
+        "pattern": "^[_a-z][_a-z0-9]*$"
+    })
+}
+
+macro_rules! impl_json_schema {
+    ($($ty:ty),*) => {
+        $(
+            impl JsonSchema for $ty {
+                fn schema_name() -> Cow<'static, str> {
+                    "SnakeCase".into()
+                }
+
+                fn schema_id() -> Cow<'static, str> {
+                    "snake_case::SnakeCase".into()
+                }
+
+                fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                    snake_case_schema()
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema!(SnakeCase, SnakeCaseRef<'_>, SnakeStr);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_schema() {
+        let expected = serde_json::json!({
+            "type": "string",
+            "pattern": "^[_a-z][_a-z0-9]*$"
+        });
+        let mut generator = SchemaGenerator::default();
+        assert_eq!(SnakeCase::json_schema(&mut generator), expected);
+        assert_eq!(SnakeCaseRef::json_schema(&mut generator), expected);
+        assert_eq!(SnakeCase::schema_id(), SnakeCaseRef::schema_id());
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(feature = "alloc")]
+use crate::{SnakeCase, SnakeCaseRef, SnakeStr};
+
+/// Is the given string a non-empty SCREAMING_SNAKE_CASE string?
+/// In particular, does it match  ^[_A-Z][_A-Z0-9]*$  ?
+pub const fn is_screaming_snake_case(string: &str) -> bool {
+    let bytes = string.as_bytes();
+    if bytes.is_empty() || !(bytes[0] == b'_' || bytes[0].is_ascii_uppercase()) {
+        return false;
+    }
+    let mut i = 1;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if !(b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_') {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Only one possible error: the given string was not valid SCREAMING_SNAKE_CASE.
+#[derive(Clone, Debug)]
+pub struct InvalidScreamingSnakeCase;
+
+case_types! {
+    /// An owning string type that can only contain valid SCREAMING_SNAKE_CASE.
+    /// In other words, it always matches  ^[_A-Z][_A-Z0-9]*$
+    /// * Non-empty
+    /// * Starts with an upper case ASCII letter or underscore
+    /// * Contains only upper case ASCII letters, underscores and digits
+    pub struct ScreamingSnakeCase;
+
+    /// An non-owning string type that can only refer to string containing valid SCREAMING_SNAKE_CASE.
+    /// In other words, it always matches  ^[_A-Z][_A-Z0-9]*$
+    /// * Non-empty
+    /// * Starts with an upper case ASCII letter or underscore
+    /// * Contains only upper case ASCII letters, underscores and digits
+    pub struct ScreamingSnakeCaseRef;
+
+    error: InvalidScreamingSnakeCase,
+    validator: is_screaming_snake_case,
+    expected: "SCREAMING_SNAKE_CASE",
+}
+
+#[cfg(feature = "alloc")]
+impl ScreamingSnakeCase {
+    /// `DATABASE_URL` -> `database_url`
+    pub fn to_snake(&self) -> SnakeCase {
+        self.as_ref().to_snake()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ScreamingSnakeCaseRef<'_> {
+    /// `DATABASE_URL` -> `database_url`
+    pub fn to_snake(&self) -> SnakeCase {
+        SnakeCase::from_string_unchecked(self.as_str().to_ascii_lowercase())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SnakeStr {
+    /// `database_url` -> `DATABASE_URL`
+    pub fn to_screaming(&self) -> ScreamingSnakeCase {
+        ScreamingSnakeCase(self.as_str().to_ascii_uppercase())
+    }
+
+    /// The name of the environment variable for this key: `database_url` -> `DATABASE_URL`.
+    ///
+    /// The same as [`Self::to_screaming`]. See also [`crate::env`].
+    pub fn to_env_key(&self) -> ScreamingSnakeCase {
+        self.to_screaming()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<ScreamingSnakeCaseRef<'_>> for SnakeCase {
+    fn from(screaming: ScreamingSnakeCaseRef<'_>) -> Self {
+        screaming.to_snake()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&ScreamingSnakeCase> for SnakeCase {
+    fn from(screaming: &ScreamingSnakeCase) -> Self {
+        screaming.to_snake()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<ScreamingSnakeCase> for SnakeCase {
+    fn from(screaming: ScreamingSnakeCase) -> Self {
+        screaming.to_snake()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<SnakeCaseRef<'_>> for ScreamingSnakeCase {
+    fn from(snake: SnakeCaseRef<'_>) -> Self {
+        snake.to_screaming()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&SnakeCase> for ScreamingSnakeCase {
+    fn from(snake: &SnakeCase) -> Self {
+        snake.to_screaming()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<SnakeCase> for ScreamingSnakeCase {
+    fn from(snake: SnakeCase) -> Self {
+        snake.to_screaming()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn screaming_snake_case() {
+        assert!(is_screaming_snake_case("MAX_VALUE_2"));
+        assert!(is_screaming_snake_case("_"));
+        assert!(!is_screaming_snake_case(""));
+        assert!(!is_screaming_snake_case("2X"));
+        assert!(!is_screaming_snake_case("Max_VALUE"));
+        assert!(ScreamingSnakeCaseRef::try_from_str("max_value").is_err());
+    }
+
+    #[test]
+    fn screaming_snake_conversions() {
+        let snake = SnakeCase::try_from_str("_database_url2").unwrap();
+        let screaming = snake.to_screaming();
+        assert_eq!(screaming, "_DATABASE_URL2");
+        assert_eq!(screaming.to_snake(), snake);
+    }
+}
@@ -0,0 +1,112 @@
+use alloc::string::String;
+
+use serde::{de::Deserialize as _, ser::Error as _, Deserializer, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+
+use crate::SnakeCase;
+
+/// Validates a `String` field as snake_case with `#[serde_as(as = "AsSnakeCase")]`.
+///
+/// For when the field type isn't yours to change to [`SnakeCase`]:
+///
+/// ```
+/// use serde_with::serde_as;
+/// use snake_case::AsSnakeCase;
+///
+/// #[serde_as]
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Column {
+///     #[serde_as(as = "AsSnakeCase")]
+///     name: String,
+///
+///     #[serde_as(as = "Option<AsSnakeCase>")]
+///     alias: Option<String>,
+/// }
+///
+/// assert!(serde_json::from_str::<Column>(r#"{ "name": "user_id", "alias": null }"#).is_ok());
+/// assert!(serde_json::from_str::<Column>(r#"{ "name": "userId", "alias": null }"#).is_err());
+/// ```
+///
+/// Serializing a string that isn't snake_case is an error too.
+pub struct AsSnakeCase;
+
+impl<'de> DeserializeAs<'de, String> for AsSnakeCase {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        SnakeCase::deserialize(deserializer).map(SnakeCase::into_string)
+    }
+}
+
+impl SerializeAs<String> for AsSnakeCase {
+    fn serialize_as<S: Serializer>(source: &String, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::validate(source).map_err(S::Error::custom)?;
+        serializer.serialize_str(source)
+    }
+}
+
+/// Like [`AsSnakeCase`], but converting other case styles to snake_case,
+/// like [`crate::lenient`] does for [`SnakeCase`] fields.
+///
+/// ```
+/// use serde_with::serde_as;
+/// use snake_case::AsSnakeCaseLenient;
+///
+/// #[serde_as]
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Event {
+///     #[serde_as(as = "AsSnakeCaseLenient")]
+///     kind: String,
+/// }
+///
+/// let event: Event = serde_json::from_str(r#"{ "kind": "userSignedUp" }"#).unwrap();
+/// assert_eq!(event.kind, "user_signed_up");
+/// ```
+///
+/// Serializing converts too, so `kind: "UserSignedUp".into()` is written as `"user_signed_up"`.
+pub struct AsSnakeCaseLenient;
+
+impl<'de> DeserializeAs<'de, String> for AsSnakeCaseLenient {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        crate::lenient::deserialize(deserializer).map(SnakeCase::into_string)
+    }
+}
+
+impl SerializeAs<String> for AsSnakeCaseLenient {
+    fn serialize_as<S: Serializer>(source: &String, serializer: S) -> Result<S::Ok, S::Error> {
+        let snake = SnakeCase::from_any_case(source).map_err(S::Error::custom)?;
+        serializer.serialize_str(snake.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[serde_with::serde_as]
+    #[derive(Debug, serde::Deserialize, serde::Serialize)]
+    struct Strict(#[serde_as(as = "AsSnakeCase")] String);
+
+    #[serde_with::serde_as]
+    #[derive(Debug, serde::Deserialize, serde::Serialize)]
+    struct Lenient(#[serde_as(as = "AsSnakeCaseLenient")] String);
+
+    #[test]
+    fn serde_with() {
+        assert_eq!(serde_json::from_str::<Strict>(r#""a_b""#).unwrap().0, "a_b");
+        assert!(serde_json::to_string(&Strict("a-b".into())).is_err());
+        assert_eq!(
+            serde_json::to_string(&Strict("a_b".into())).unwrap(),
+            r#""a_b""#
+        );
+
+        assert_eq!(
+            serde_json::from_str::<Lenient>(r#""a-b""#).unwrap().0,
+            "a_b"
+        );
+        assert!(serde_json::from_str::<Lenient>(r#""a b""#).is_err());
+        assert_eq!(
+            serde_json::to_string(&Lenient("HttpServer".into())).unwrap(),
+            r#""http_server""#
+        );
+        assert!(serde_json::to_string(&Lenient("--".into())).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use alloc::string::String;
+
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization as _};
+
+use crate::{convert, InvalidSnakeCase, SnakeCase};
+
+/// Letters that don't decompose into an ASCII letter plus accents.
+fn transliterate(c: char) -> Option<&'static str> {
+    Some(match c {
+        'ß' | 'ẞ' => "ss",
+        'æ' | 'Æ' => "ae",
+        'œ' | 'Œ' => "oe",
+        'ø' | 'Ø' => "o",
+        'đ' | 'Đ' | 'ð' | 'Ð' => "d",
+        'ł' | 'Ł' => "l",
+        'þ' | 'Þ' => "th",
+        'ı' => "i",
+        _ => return None,
+    })
+}
+
+impl SnakeCase {
+    /// Make an identifier out of arbitrary text, like a title.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCase;
+    /// assert_eq!(SnakeCase::slugify("Héllo, Wörld! 2024").unwrap(), "hello_world_2024");
+    /// assert_eq!(SnakeCase::slugify("Straße in Łódź").unwrap(), "strasse_in_lodz");
+    /// assert_eq!(SnakeCase::slugify("2 Fast 2 Furious").unwrap(), "_2_fast_2_furious");
+    /// ```
+    ///
+    /// Accents are stripped and a few other letters are transliterated (`ß` -> `ss`).
+    /// Anything else that is not an ASCII letter or digit separates words, and the words are joined
+    /// with underscores. Unlike [`Self::from_any_case`] this doesn't split `camelCase` words, since text isn't code.
+    ///
+    /// Fails with [`InvalidSnakeCase::Empty`] if nothing is left, e.g. for text in a non-Latin script.
+    pub fn slugify(text: &str) -> Result<SnakeCase, InvalidSnakeCase> {
+        let mut ascii = String::with_capacity(text.len());
+        for c in text.nfkd() {
+            if c.is_ascii() {
+                ascii.push(c.to_ascii_lowercase());
+            } else if let Some(replacement) = transliterate(c) {
+                ascii.push_str(replacement);
+            } else if !is_combining_mark(c) {
+                ascii.push(' ');
+            }
+        }
+        convert::to_snake_case_string(&ascii)
+            .map(SnakeCase::from_string_unchecked)
+            .ok_or(InvalidSnakeCase::Empty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify() {
+        let slug = |text| SnakeCase::slugify(text).map(SnakeCase::into_string);
+        assert_eq!(slug("Crème Brûlée"), Ok("creme_brulee".into()));
+        assert_eq!(slug("ﬁnal Ｆｏｒｍ"), Ok("final_form".into()));
+        assert_eq!(slug("iPhone 15 — review"), Ok("iphone_15_review".into()));
+        assert_eq!(slug("Ærøskøbing"), Ok("aeroskobing".into()));
+        assert_eq!(slug("日本語 wiki"), Ok("wiki".into()));
+        assert_eq!(slug("日本語"), Err(InvalidSnakeCase::Empty));
+        assert_eq!(slug(""), Err(InvalidSnakeCase::Empty));
+    }
+}
@@ -0,0 +1,282 @@
+use core::{fmt, ops::Deref};
+
+use crate::{validate, validate_word, InvalidSnakeCase, SnakeCaseRef, SnakeStr};
+
+/// An owning snake_case string stored in a fixed-size `[u8; N]`, so it needs no allocator.
+///
+/// Anything longer than `N` bytes is rejected.
+///
+/// ```
+/// # use snake_case::SnakeCaseArray;
+/// # fn main() -> Result<(), snake_case::SnakeCaseArrayError> {
+/// let mut sensor = SnakeCaseArray::<16>::try_from_str("imu")?;
+/// sensor.push_word("accel")?.push_word("x")?;
+/// assert_eq!(sensor, "imu_accel_x");
+/// assert!(sensor.push_word("calibrated").is_err()); // would not fit
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct SnakeCaseArray<const N: usize> {
+    len: usize,
+    bytes: [u8; N],
+}
+
+/// Why a [`SnakeCaseArray`] could not be created or extended.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SnakeCaseArrayError {
+    /// The string was not valid snake_case.
+    Invalid(InvalidSnakeCase),
+
+    /// The string was valid, but needed `len` bytes of the available `capacity`.
+    TooLong { len: usize, capacity: usize },
+}
+
+impl fmt::Display for SnakeCaseArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(err) => err.fmt(f),
+            Self::TooLong { len, capacity } => write!(
+                f,
+                "snake_case of {} bytes does not fit in a capacity of {} bytes",
+                len, capacity
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SnakeCaseArrayError {}
+
+impl From<InvalidSnakeCase> for SnakeCaseArrayError {
+    fn from(err: InvalidSnakeCase) -> Self {
+        Self::Invalid(err)
+    }
+}
+
+impl<const N: usize> SnakeCaseArray<N> {
+    /// The maximum length, in bytes.
+    pub const CAPACITY: usize = N;
+
+    pub const fn try_from_str(s: &str) -> Result<SnakeCaseArray<N>, SnakeCaseArrayError> {
+        if let Err(err) = validate(s) {
+            return Err(SnakeCaseArrayError::Invalid(err));
+        }
+        if s.len() > N {
+            return Err(SnakeCaseArrayError::TooLong {
+                len: s.len(),
+                capacity: N,
+            });
+        }
+
+        let mut bytes = [0; N];
+        let mut i = 0;
+        while i < s.len() {
+            bytes[i] = s.as_bytes()[i];
+            i += 1;
+        }
+        Ok(SnakeCaseArray {
+            len: s.len(),
+            bytes,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        // SAFETY: the first `len` bytes are always valid snake_case, which is ASCII.
+        unsafe { core::str::from_utf8_unchecked(&self.bytes[..self.len]) }
+    }
+
+    pub fn as_ref(&self) -> SnakeCaseRef<'_> {
+        SnakeCaseRef(self.as_str())
+    }
+
+    /// Append an underscore and a word, which must be non-empty and consist of only lower case ASCII letters and digits.
+    ///
+    /// Like [`crate::SnakeCaseBuilder::push_word`], error offsets refer to where the offending character would have ended up.
+    /// On error, `self` is left unchanged.
+    pub fn push_word(&mut self, word: &str) -> Result<&mut Self, SnakeCaseArrayError> {
+        validate_word(word, self.len + 1)?;
+        let new_len = self.len + 1 + word.len();
+        if new_len > N {
+            return Err(SnakeCaseArrayError::TooLong {
+                len: new_len,
+                capacity: N,
+            });
+        }
+
+        self.bytes[self.len] = b'_';
+        self.bytes[self.len + 1..new_len].copy_from_slice(word.as_bytes());
+        self.len = new_len;
+        Ok(self)
+    }
+}
+
+impl<const N: usize> core::convert::TryFrom<&str> for SnakeCaseArray<N> {
+    type Error = SnakeCaseArrayError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        SnakeCaseArray::try_from_str(s)
+    }
+}
+
+impl<const N: usize> core::str::FromStr for SnakeCaseArray<N> {
+    type Err = SnakeCaseArrayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SnakeCaseArray::try_from_str(s)
+    }
+}
+
+impl<const N: usize> Deref for SnakeCaseArray<N> {
+    type Target = SnakeStr;
+
+    fn deref(&self) -> &SnakeStr {
+        // SAFETY: `SnakeCaseArray` is always valid snake_case.
+        unsafe { SnakeStr::from_str_unchecked(self.as_str()) }
+    }
+}
+
+impl<const N: usize> AsRef<SnakeStr> for SnakeCaseArray<N> {
+    fn as_ref(&self) -> &SnakeStr {
+        self
+    }
+}
+
+impl<const N: usize> AsRef<str> for SnakeCaseArray<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> core::borrow::Borrow<str> for SnakeCaseArray<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Debug for SnakeCaseArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl<const N: usize> fmt::Display for SnakeCaseArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+// Only the used bytes count:
+
+impl<const N: usize> PartialEq for SnakeCaseArray<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for SnakeCaseArray<N> {}
+
+impl<const N: usize> PartialOrd for SnakeCaseArray<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for SnakeCaseArray<N> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> core::hash::Hash for SnakeCaseArray<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<const N: usize> PartialEq<str> for SnakeCaseArray<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for SnakeCaseArray<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> PartialEq<SnakeCaseRef<'_>> for SnakeCaseArray<N> {
+    fn eq(&self, other: &SnakeCaseRef<'_>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for SnakeCaseArray<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Works without an allocator, as long as the deserializer can hand out a `&str`.
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for SnakeCaseArray<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor<const N: usize>;
+
+        impl<'de, const N: usize> serde::de::Visitor<'de> for Visitor<N> {
+            type Value = SnakeCaseArray<N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "snake_case of at most {} bytes", N)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Self::Value, E> {
+                SnakeCaseArray::try_from_str(s).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array() {
+        const ID: SnakeCaseArray<8> = match SnakeCaseArray::try_from_str("motor") {
+            Ok(id) => id,
+            Err(_) => panic!(),
+        };
+        let mut id = ID;
+        assert_eq!(id.word_count(), 1);
+        id.push_word("2").unwrap();
+        assert_eq!(id, "motor_2");
+        assert_eq!(
+            id.push_word("x").unwrap_err(),
+            SnakeCaseArrayError::TooLong {
+                len: 9,
+                capacity: 8
+            }
+        );
+        assert_eq!(
+            id.push_word("X").unwrap_err(),
+            SnakeCaseArrayError::Invalid(InvalidSnakeCase::UppercaseChar { offset: 8 })
+        );
+        assert_eq!(id, "motor_2");
+
+        assert!(SnakeCaseArray::<4>::try_from_str("motor").is_err());
+        assert!(SnakeCaseArray::<8>::try_from_str("Motor").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let id: SnakeCaseArray<8> = serde_json::from_str(r#""motor_2""#).unwrap();
+        assert_eq!(serde_json::to_string(&id).unwrap(), r#""motor_2""#);
+        assert!(serde_json::from_str::<SnakeCaseArray<4>>(r#""motor_2""#).is_err());
+    }
+}
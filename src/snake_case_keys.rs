@@ -0,0 +1,413 @@
+use alloc::string::String;
+use core::fmt;
+
+use serde::de::{
+    DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+
+use crate::{convert, is_snake_case};
+
+/// A [`Deserializer`] that converts every map key to snake_case, at any depth, before deserializing it.
+///
+/// For consuming JSON (or anything else serde reads) that mixes `camelCase`, `kebab-case` and `snake_case` keys
+/// into plain (even `deny_unknown_fields`) structs:
+///
+/// ```
+/// use serde::Deserialize as _;
+/// use snake_case::SnakeCaseKeys;
+///
+/// #[derive(serde::Deserialize)]
+/// #[serde(deny_unknown_fields)]
+/// struct Config {
+///     max_depth: u32,
+///     retry_policy: Retry,
+/// }
+///
+/// #[derive(serde::Deserialize)]
+/// #[serde(deny_unknown_fields)]
+/// struct Retry {
+///     max_attempts: u32,
+/// }
+///
+/// let json = r#"{ "maxDepth": 3, "retry-policy": { "MaxAttempts": 5 } }"#;
+/// let mut json = serde_json::Deserializer::from_str(json);
+/// let config = Config::deserialize(SnakeCaseKeys(&mut json)).unwrap();
+/// assert_eq!(config.max_depth, 3);
+/// assert_eq!(config.retry_policy.max_attempts, 5);
+/// ```
+///
+/// Keys are converted like [`crate::SnakeCase::from_any_case`], except for keys that already are snake_case
+/// (so `_id` stays `_id`) and keys without any ASCII letters or digits, which are left alone.
+/// Values, including strings and enum variant names, are never touched.
+#[derive(Clone, Copy, Debug)]
+pub struct SnakeCaseKeys<D>(pub D);
+
+fn convert_key(key: &str) -> Option<String> {
+    if is_snake_case(key) {
+        None
+    } else {
+        convert::to_snake_case_string(key)
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($wrap_visitor:ident: $($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error> {
+                self.0.$method($($arg,)* $wrap_visitor(visitor))
+            }
+        )*
+    };
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E: serde::de::Error>(self, v: $ty) -> Result<V::Value, E> {
+                self.0.$method(v)
+            }
+        )*
+    };
+}
+
+macro_rules! deserializer_methods {
+    ($wrap_visitor:ident) => {
+        forward_deserialize! {
+            $wrap_visitor:
+            deserialize_any(),
+            deserialize_bool(),
+            deserialize_i8(),
+            deserialize_i16(),
+            deserialize_i32(),
+            deserialize_i64(),
+            deserialize_i128(),
+            deserialize_u8(),
+            deserialize_u16(),
+            deserialize_u32(),
+            deserialize_u64(),
+            deserialize_u128(),
+            deserialize_f32(),
+            deserialize_f64(),
+            deserialize_char(),
+            deserialize_str(),
+            deserialize_string(),
+            deserialize_bytes(),
+            deserialize_byte_buf(),
+            deserialize_option(),
+            deserialize_unit(),
+            deserialize_unit_struct(name: &'static str),
+            deserialize_newtype_struct(name: &'static str),
+            deserialize_seq(),
+            deserialize_tuple(len: usize),
+            deserialize_tuple_struct(name: &'static str, len: usize),
+            deserialize_map(),
+            deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+            deserialize_identifier(),
+            deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+            deserialize_ignored_any(),
+        }
+
+        fn is_human_readable(&self) -> bool {
+            self.0.is_human_readable()
+        }
+    };
+}
+
+macro_rules! scalar_visits {
+    () => {
+        forward_visit! {
+            visit_bool(bool),
+            visit_i8(i8),
+            visit_i16(i16),
+            visit_i32(i32),
+            visit_i64(i64),
+            visit_i128(i128),
+            visit_u8(u8),
+            visit_u16(u16),
+            visit_u32(u32),
+            visit_u64(u64),
+            visit_u128(u128),
+            visit_f32(f32),
+            visit_f64(f64),
+            visit_char(char),
+            visit_bytes(&[u8]),
+            visit_borrowed_bytes(&'de [u8]),
+            visit_byte_buf(alloc::vec::Vec<u8>),
+        }
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.expecting(f)
+        }
+
+        fn visit_none<E: serde::de::Error>(self) -> Result<V::Value, E> {
+            self.0.visit_none()
+        }
+
+        fn visit_unit<E: serde::de::Error>(self) -> Result<V::Value, E> {
+            self.0.visit_unit()
+        }
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for SnakeCaseKeys<D> {
+    type Error = D::Error;
+
+    deserializer_methods!(Wrap);
+}
+
+/// A visitor, map, seq, enum or seed whose nested deserializers are wrapped in [`SnakeCaseKeys`].
+struct Wrap<T>(T);
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for Wrap<V> {
+    type Value = V::Value;
+
+    scalar_visits!();
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<V::Value, E> {
+        self.0.visit_str(v)
+    }
+
+    fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<V::Value, E> {
+        self.0.visit_borrowed_str(v)
+    }
+
+    fn visit_string<E: serde::de::Error>(self, v: String) -> Result<V::Value, E> {
+        self.0.visit_string(v)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<V::Value, D::Error> {
+        self.0.visit_some(SnakeCaseKeys(deserializer))
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<V::Value, D::Error> {
+        self.0.visit_newtype_struct(SnakeCaseKeys(deserializer))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<V::Value, A::Error> {
+        self.0.visit_seq(Wrap(seq))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<V::Value, A::Error> {
+        self.0.visit_map(Wrap(map))
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<V::Value, A::Error> {
+        self.0.visit_enum(Wrap(data))
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Wrap<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        self.0.deserialize(SnakeCaseKeys(deserializer))
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for Wrap<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, A::Error> {
+        self.0.next_element_seed(Wrap(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for Wrap<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        self.0.next_key_seed(KeySeed(seed))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, A::Error> {
+        self.0.next_value_seed(Wrap(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+impl<'de, A: EnumAccess<'de>> EnumAccess<'de> for Wrap<A> {
+    type Error = A::Error;
+    type Variant = Wrap<A::Variant>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), A::Error> {
+        // The variant name is left as it is, only its contents are wrapped:
+        let (value, variant) = self.0.variant_seed(seed)?;
+        Ok((value, Wrap(variant)))
+    }
+}
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for Wrap<A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.0.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, A::Error> {
+        self.0.newtype_variant_seed(Wrap(seed))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, A::Error> {
+        self.0.tuple_variant(len, Wrap(visitor))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        self.0.struct_variant(fields, Wrap(visitor))
+    }
+}
+
+/// Deserializes a map key, converting it to snake_case if it is a string.
+struct KeySeed<S>(S);
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for KeySeed<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        self.0.deserialize(KeyDeserializer(deserializer))
+    }
+}
+
+struct KeyDeserializer<D>(D);
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for KeyDeserializer<D> {
+    type Error = D::Error;
+
+    deserializer_methods!(KeyVisitor);
+}
+
+struct KeyVisitor<V>(V);
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for KeyVisitor<V> {
+    type Value = V::Value;
+
+    scalar_visits!();
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<V::Value, E> {
+        match convert_key(v) {
+            Some(key) => self.0.visit_string(key),
+            None => self.0.visit_str(v),
+        }
+    }
+
+    fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<V::Value, E> {
+        match convert_key(v) {
+            Some(key) => self.0.visit_string(key),
+            None => self.0.visit_borrowed_str(v),
+        }
+    }
+
+    fn visit_string<E: serde::de::Error>(self, v: String) -> Result<V::Value, E> {
+        match convert_key(&v) {
+            Some(key) => self.0.visit_string(key),
+            None => self.0.visit_string(v),
+        }
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<V::Value, D::Error> {
+        self.0.visit_some(KeyDeserializer(deserializer))
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<V::Value, D::Error> {
+        self.0.visit_newtype_struct(KeyDeserializer(deserializer))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<V::Value, A::Error> {
+        self.0.visit_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<V::Value, A::Error> {
+        self.0.visit_map(map)
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<V::Value, A::Error> {
+        self.0.visit_enum(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, vec, vec::Vec};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Outer {
+        user_id: u32,
+        tags: Vec<Inner>,
+        extra: Option<Inner>,
+        kind: Kind,
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Inner {
+        tag_name: String,
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    enum Kind {
+        Struct { field_name: u8 },
+    }
+
+    fn from_json<'de, T: serde::Deserialize<'de>>(json: &'de str) -> Result<T, serde_json::Error> {
+        T::deserialize(SnakeCaseKeys(&mut serde_json::Deserializer::from_str(json)))
+    }
+
+    #[test]
+    fn snake_case_keys() {
+        let json = r#"{
+            "userId": 1,
+            "tags": [{ "TagName": "firstTag" }],
+            "extra": { "tag-name": "x" },
+            "kind": { "Struct": { "fieldName": 2 } }
+        }"#;
+        let outer: Outer = from_json(json).unwrap();
+        assert_eq!(
+            outer,
+            Outer {
+                user_id: 1,
+                tags: vec![Inner {
+                    tag_name: "firstTag".into() // values are left alone
+                }],
+                extra: Some(Inner {
+                    tag_name: "x".into()
+                }),
+                kind: Kind::Struct { field_name: 2 },
+            }
+        );
+
+        let map: BTreeMap<String, u8> =
+            from_json(r#"{ "_id": 1, "HTTPCode": 2, "--": 3 }"#).unwrap();
+        assert_eq!(map.keys().collect::<Vec<_>>(), ["--", "_id", "http_code"]);
+
+        let borrowed: BTreeMap<&str, u8> = from_json(r#"{ "a_b": 1 }"#).unwrap();
+        assert_eq!(borrowed["a_b"], 1);
+    }
+}
@@ -0,0 +1,116 @@
+use core::fmt;
+
+use crate::SnakeCaseRef;
+
+/// A read-only map from snake_case keys to values, stored as a sorted slice.
+///
+/// Build one with `snake_case_map!` (with the `"derive"` feature),
+/// which checks, de-duplicates and sorts the keys at compile time:
+///
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use snake_case::{snake_case_map, SnakeCaseMap};
+///
+/// const LIMITS: SnakeCaseMap<'static, u32> = snake_case_map! {
+///     "retry_count" => 5,
+///     "max_depth" => 3,
+/// };
+/// assert_eq!(LIMITS.get("max_depth"), Some(&3));
+/// assert_eq!(LIMITS.get("timeout"), None);
+/// # }
+/// ```
+///
+/// Lookups are a binary search, so they never hash or allocate.
+pub struct SnakeCaseMap<'a, V> {
+    // Sorted by key, without duplicates.
+    #[doc(hidden)]
+    pub __entries: &'a [(SnakeCaseRef<'static>, V)],
+}
+
+impl<'a, V> SnakeCaseMap<'a, V> {
+    /// Returns `None` if the keys are not sorted, or not unique.
+    pub fn from_sorted(entries: &'a [(SnakeCaseRef<'static>, V)]) -> Option<Self> {
+        if entries.windows(2).all(|pair| pair[0].0 < pair[1].0) {
+            Some(Self { __entries: entries })
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&'a V> {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    pub fn get_key_value(&self, key: &str) -> Option<(SnakeCaseRef<'static>, &'a V)> {
+        let entries = self.__entries;
+        let index = entries
+            .binary_search_by(|(k, _)| k.as_str().cmp(key))
+            .ok()?;
+        let (key, value) = &entries[index];
+        Some((*key, value))
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.__entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.__entries.is_empty()
+    }
+
+    /// In key order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (SnakeCaseRef<'static>, &'a V)> + 'a {
+        self.__entries.iter().map(|(key, value)| (*key, value))
+    }
+
+    /// In sorted order.
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = SnakeCaseRef<'static>> + 'a {
+        self.__entries.iter().map(|(key, _)| *key)
+    }
+
+    /// In key order.
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &'a V> + 'a {
+        self.__entries.iter().map(|(_, value)| value)
+    }
+}
+
+impl<V> Clone for SnakeCaseMap<'_, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V> Copy for SnakeCaseMap<'_, V> {}
+
+impl<V: fmt::Debug> fmt::Debug for SnakeCaseMap<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn map() {
+        let key = SnakeCaseRef::from_str_or_panic;
+        let entries = [(key("a"), 1), (key("b"), 2), (key("c_d"), 3)];
+        let map = SnakeCaseMap::from_sorted(&entries).unwrap();
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get("c_d"), Some(&3));
+        assert_eq!(map.get("c"), None);
+        assert_eq!(map.keys().collect::<Vec<_>>(), ["a", "b", "c_d"]);
+
+        let unsorted = [(key("b"), 1), (key("a"), 2)];
+        assert!(SnakeCaseMap::from_sorted(&unsorted).is_none());
+        let duplicates = [(key("a"), 1), (key("a"), 2)];
+        assert!(SnakeCaseMap::from_sorted(&duplicates).is_none());
+    }
+}
@@ -0,0 +1,246 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::SnakeCaseRef;
+#[cfg(feature = "alloc")]
+use crate::SnakeStr;
+
+/// Is the given string a non-empty sequence of snake_case segments,
+/// separated by either `.` or `::` (but not both)?
+///
+/// `foo.bar_baz.qux` and `foo::bar_baz` are valid, `foo.bar::baz`, `foo..bar` and `.foo` are not.
+pub const fn is_snake_case_path(string: &str) -> bool {
+    const NONE: u8 = 0;
+    const DOT: u8 = 1;
+    const COLONS: u8 = 2;
+
+    let bytes = string.as_bytes();
+    let mut separator = NONE;
+    let mut i = 0;
+    loop {
+        // a segment: ^[_a-z][_a-z0-9]*
+        if i >= bytes.len() || !(bytes[i] == b'_' || bytes[i].is_ascii_lowercase()) {
+            return false;
+        }
+        i += 1;
+        while i < bytes.len()
+            && (bytes[i] == b'_' || bytes[i].is_ascii_lowercase() || bytes[i].is_ascii_digit())
+        {
+            i += 1;
+        }
+
+        if i == bytes.len() {
+            return true;
+        }
+
+        // a separator, the same as any previous one:
+        if bytes[i] == b'.' && separator != COLONS {
+            separator = DOT;
+            i += 1;
+        } else if bytes[i] == b':'
+            && i + 1 < bytes.len()
+            && bytes[i + 1] == b':'
+            && separator != DOT
+        {
+            separator = COLONS;
+            i += 2;
+        } else {
+            return false;
+        }
+    }
+}
+
+/// Only one possible error: the given string was not a valid snake_case path.
+#[derive(Clone, Debug)]
+pub struct InvalidSnakeCasePath;
+
+case_types! {
+    /// An owning string type that can only contain a valid snake_case path,
+    /// i.e. snake_case segments separated by either `.` or `::`.
+    /// * Non-empty
+    /// * Every segment is valid [`crate::SnakeCase`]
+    /// * All separators are the same
+    ///
+    /// ```
+    /// # use snake_case::SnakeCasePath;
+    /// let key = SnakeCasePath::try_from_str("server.http.max_connections").unwrap();
+    /// assert_eq!(key.leaf(), "max_connections");
+    /// assert_eq!(key.parent().unwrap(), "server.http");
+    /// ```
+    pub struct SnakeCasePath;
+
+    /// An non-owning string type that can only refer to a valid snake_case path,
+    /// i.e. snake_case segments separated by either `.` or `::`.
+    /// * Non-empty
+    /// * Every segment is valid [`crate::SnakeCase`]
+    /// * All separators are the same
+    pub struct SnakeCasePathRef;
+
+    error: InvalidSnakeCasePath,
+    validator: is_snake_case_path,
+    expected: "a snake_case path",
+}
+
+#[cfg(feature = "alloc")]
+impl SnakeCasePath {
+    /// The snake_case segments, from the root to the leaf.
+    pub fn segments(&self) -> impl DoubleEndedIterator<Item = SnakeCaseRef<'_>> {
+        self.as_ref().segments()
+    }
+
+    /// Everything but the last segment, or `None` if there is only one segment.
+    pub fn parent(&self) -> Option<SnakeCasePathRef<'_>> {
+        self.as_ref().parent()
+    }
+
+    /// The last segment.
+    pub fn leaf(&self) -> SnakeCaseRef<'_> {
+        self.as_ref().leaf()
+    }
+
+    /// `.` or `::`.
+    ///
+    /// A path with only one segment uses `.`.
+    pub fn separator(&self) -> &'static str {
+        self.as_ref().separator()
+    }
+
+    /// Append a segment, using the same separator as the rest of the path.
+    pub fn join(&self, segment: impl AsRef<SnakeStr>) -> SnakeCasePath {
+        self.as_ref().join(segment)
+    }
+
+    /// Append a segment in-place, using the same separator as the rest of the path.
+    pub fn push(&mut self, segment: impl AsRef<SnakeStr>) {
+        let separator = self.separator();
+        self.0.push_str(separator);
+        self.0.push_str(segment.as_ref().as_str());
+    }
+}
+
+impl<'a> SnakeCasePathRef<'a> {
+    /// The snake_case segments, from the root to the leaf.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCasePathRef;
+    /// let path = SnakeCasePathRef::try_from_str("std::collections::hash_map").unwrap();
+    /// let segments: Vec<_> = path.segments().map(|s| s.as_str()).collect();
+    /// assert_eq!(segments, ["std", "collections", "hash_map"]);
+    /// ```
+    pub fn segments(&self) -> impl DoubleEndedIterator<Item = SnakeCaseRef<'a>> {
+        // Segments are never empty, so splitting on single colons
+        // and skipping the empty pieces between `::` is the same as splitting on `::`.
+        self.0
+            .split(['.', ':'])
+            .filter(|segment| !segment.is_empty())
+            .map(SnakeCaseRef)
+    }
+
+    /// Everything but the last segment, or `None` if there is only one segment.
+    pub fn parent(&self) -> Option<SnakeCasePathRef<'a>> {
+        let end = self.0.rfind(['.', ':'])?;
+        let end = if self.separator() == "::" {
+            end - 1
+        } else {
+            end
+        };
+        Some(SnakeCasePathRef(&self.0[..end]))
+    }
+
+    /// The last segment.
+    pub fn leaf(&self) -> SnakeCaseRef<'a> {
+        match self.0.rfind(['.', ':']) {
+            Some(i) => SnakeCaseRef(&self.0[i + 1..]),
+            None => SnakeCaseRef(self.0),
+        }
+    }
+
+    /// `.` or `::`.
+    ///
+    /// A path with only one segment uses `.`.
+    pub fn separator(&self) -> &'static str {
+        if self.0.contains(':') {
+            "::"
+        } else {
+            "."
+        }
+    }
+
+    /// Append a segment, using the same separator as the rest of the path.
+    #[cfg(feature = "alloc")]
+    pub fn join(&self, segment: impl AsRef<SnakeStr>) -> SnakeCasePath {
+        let segment = segment.as_ref().as_str();
+        let separator = self.separator();
+        let mut string = String::with_capacity(self.0.len() + separator.len() + segment.len());
+        string.push_str(self.0);
+        string.push_str(separator);
+        string.push_str(segment);
+        SnakeCasePath(string)
+    }
+}
+
+/// A single snake_case string is a path with one segment.
+impl<'a> From<SnakeCaseRef<'a>> for SnakeCasePathRef<'a> {
+    fn from(snake: SnakeCaseRef<'a>) -> Self {
+        SnakeCasePathRef(snake.as_str())
+    }
+}
+
+/// A single snake_case string is a path with one segment.
+#[cfg(feature = "alloc")]
+impl From<crate::SnakeCase> for SnakeCasePath {
+    fn from(snake: crate::SnakeCase) -> Self {
+        SnakeCasePath(snake.into_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn is_path() {
+        assert!(is_snake_case_path("foo"));
+        assert!(is_snake_case_path("foo.bar_baz.qux"));
+        assert!(is_snake_case_path("_private::foo2"));
+        assert!(!is_snake_case_path(""));
+        assert!(!is_snake_case_path(".foo"));
+        assert!(!is_snake_case_path("foo."));
+        assert!(!is_snake_case_path("foo..bar"));
+        assert!(!is_snake_case_path("foo:bar"));
+        assert!(!is_snake_case_path("foo:::bar"));
+        assert!(!is_snake_case_path("foo.bar::baz"));
+        assert!(!is_snake_case_path("foo.2d"));
+        assert!(!is_snake_case_path("foo.Bar"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn path() {
+        let path = SnakeCasePath::try_from_str("a::b_c::d").unwrap();
+        let segments: Vec<_> = path.segments().rev().map(|s| s.as_str()).collect();
+        assert_eq!(segments, ["d", "b_c", "a"]);
+        assert_eq!(path.leaf(), "d");
+        assert_eq!(path.parent().unwrap(), "a::b_c");
+        assert_eq!(path.parent().unwrap().parent().unwrap(), "a");
+        assert_eq!(path.parent().unwrap().parent().unwrap().parent(), None);
+
+        let leaf = crate::SnakeCase::try_from_str("e").unwrap();
+        assert_eq!(path.join(&leaf), "a::b_c::d::e");
+
+        let mut path = SnakeCasePath::from(leaf);
+        assert_eq!(path.leaf(), "e");
+        path.push(crate::SnakeCase::try_from_str("f").unwrap());
+        assert_eq!(path, "e.f");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let path: SnakeCasePath = serde_json::from_str(r#""foo.bar""#).unwrap();
+        assert_eq!(serde_json::to_string(&path).unwrap(), r#""foo.bar""#);
+        assert!(serde_json::from_str::<SnakeCasePath>(r#""foo.Bar""#).is_err());
+    }
+}
@@ -0,0 +1,187 @@
+use alloc::{
+    collections::{btree_map::Entry, BTreeMap},
+    string::String,
+};
+use core::fmt;
+
+use crate::{InvalidSnakeCase, SnakeCase, SnakeStr};
+
+/// A sorted set of [`SnakeCase`] names that can also catch the same name written in different case styles.
+///
+/// [`Self::insert`] works like for any set, while [`Self::insert_any_case`] converts the name first
+/// and reports if it collides with a differently spelled one, e.g. when merging identifiers
+/// from several sources:
+///
+/// ```
+/// # use snake_case::{SnakeCase, SnakeCaseSet};
+/// let mut names = SnakeCaseSet::new();
+/// assert_eq!(names.insert_any_case("user_id"), Ok(true));
+/// assert_eq!(names.insert_any_case("user_id"), Ok(false)); // same spelling, so no conflict
+///
+/// let err = names.insert_any_case("userId").unwrap_err();
+/// assert_eq!(err.to_string(), "`userId` and `user_id` are both `user_id`");
+///
+/// assert_eq!(names.insert_any_case("UserName"), Ok(true));
+/// assert!(names.contains("user_name"));
+/// ```
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct SnakeCaseSet {
+    /// Each name, and how it was first spelled.
+    names: BTreeMap<SnakeCase, String>,
+}
+
+/// Why [`SnakeCaseSet::insert_any_case`] failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SnakeCaseSetError {
+    /// The name could not be converted to snake_case, see [`SnakeCase::from_any_case`].
+    Invalid(InvalidSnakeCase),
+
+    /// The name is already in the set, spelled differently.
+    Collision {
+        snake_case: SnakeCase,
+
+        /// How it was spelled when it was first inserted.
+        existing: String,
+
+        /// How it was spelled now.
+        new: String,
+    },
+}
+
+impl fmt::Display for SnakeCaseSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(err) => err.fmt(f),
+            Self::Collision {
+                snake_case,
+                existing,
+                new,
+            } => write!(f, "`{}` and `{}` are both `{}`", new, existing, snake_case),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SnakeCaseSetError {}
+
+impl SnakeCaseSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Returns `false` if the name was already present.
+    pub fn insert(&mut self, name: SnakeCase) -> bool {
+        match self.names.entry(name) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                let spelling = String::from(entry.key().as_str());
+                entry.insert(spelling);
+                true
+            }
+        }
+    }
+
+    /// Convert the name with [`SnakeCase::from_any_case`] and insert it,
+    /// failing if the same snake_case name was inserted with a different spelling.
+    ///
+    /// Returns `Ok(false)` if exactly this spelling was already inserted.
+    pub fn insert_any_case(&mut self, name: &str) -> Result<bool, SnakeCaseSetError> {
+        let snake_case = SnakeCase::from_any_case(name).map_err(SnakeCaseSetError::Invalid)?;
+        match self.names.get(&snake_case) {
+            None => {
+                self.names.insert(snake_case, String::from(name));
+                Ok(true)
+            }
+            Some(existing) if existing == name => Ok(false),
+            Some(existing) => Err(SnakeCaseSetError::Collision {
+                snake_case,
+                existing: existing.clone(),
+                new: String::from(name),
+            }),
+        }
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains_key(name)
+    }
+
+    /// How the name was spelled when it was first inserted.
+    pub fn original_spelling(&self, name: &str) -> Option<&str> {
+        self.names.get(name).map(String::as_str)
+    }
+
+    /// Returns `false` if the name wasn't present.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.names.remove(name).is_some()
+    }
+
+    /// In sorted order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &SnakeStr> + '_ {
+        self.names.keys().map(|name| &**name)
+    }
+}
+
+impl fmt::Debug for SnakeCaseSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl Extend<SnakeCase> for SnakeCaseSet {
+    fn extend<I: IntoIterator<Item = SnakeCase>>(&mut self, iter: I) {
+        for name in iter {
+            self.insert(name);
+        }
+    }
+}
+
+impl core::iter::FromIterator<SnakeCase> for SnakeCaseSet {
+    fn from_iter<I: IntoIterator<Item = SnakeCase>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn collisions() {
+        let mut set: SnakeCaseSet = ["http_server", "max_depth"]
+            .iter()
+            .map(|name| SnakeCase::try_from_str(name).unwrap())
+            .collect();
+        assert!(!set.insert(SnakeCase::try_from_str("max_depth").unwrap()));
+        assert_eq!(set.insert_any_case("max_depth"), Ok(false));
+
+        assert_eq!(
+            set.insert_any_case("HTTPServer"),
+            Err(SnakeCaseSetError::Collision {
+                snake_case: SnakeCase::try_from_str("http_server").unwrap(),
+                existing: "http_server".into(),
+                new: "HTTPServer".into(),
+            })
+        );
+        assert_eq!(
+            set.insert_any_case("?!"),
+            Err(SnakeCaseSetError::Invalid(InvalidSnakeCase::Empty))
+        );
+
+        assert_eq!(set.insert_any_case("MAX-RETRIES"), Ok(true));
+        assert_eq!(set.original_spelling("max_retries"), Some("MAX-RETRIES"));
+        assert!(set.remove("http_server"));
+        assert_eq!(format!("{:?}", set), r#"{"max_depth", "max_retries"}"#);
+    }
+}
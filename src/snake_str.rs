@@ -0,0 +1,426 @@
+use core::{borrow::Borrow, fmt, ops::Deref};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+
+use crate::{validate, InvalidSnakeCase, SnakeCaseRef};
+#[cfg(feature = "alloc")]
+use crate::{SnakeCase, SnakeCaseStorage};
+
+/// An unsized string slice that can only contain valid snake_case.
+/// In other words, it always matches  ^[_a-z][_a-z0-9]*$
+///
+/// `SnakeStr` is to `SnakeCase` what `str` is to `String`:
+/// `SnakeCase` (and `SnakeCaseRef`) deref to it, so methods that only read the string are defined here once,
+/// and it derefs to `str`, so all the read-only `str` methods (`len`, `contains`, `split`, …) work too.
+/// `SnakeCase` implements `Borrow<SnakeStr>`, so a `HashMap<SnakeCase, V>` can be queried with a `&SnakeStr`.
+///
+/// ```
+/// # use snake_case::{SnakeCase, SnakeStr};
+/// use std::borrow::Cow;
+///
+/// let key: &SnakeStr = SnakeStr::try_from_str("hello_world").unwrap();
+/// let cow: Cow<'_, SnakeStr> = Cow::Borrowed(key);
+/// let owned: SnakeCase = cow.into_owned();
+/// assert_eq!(&*owned, key);
+/// ```
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct SnakeStr(str);
+
+impl SnakeStr {
+    pub const fn try_from_str(s: &str) -> Result<&SnakeStr, InvalidSnakeCase> {
+        match validate(s) {
+            // SAFETY: we just checked it.
+            Ok(()) => Ok(unsafe { SnakeStr::from_str_unchecked(s) }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// # Safety
+    /// `s` must be valid snake_case, i.e. `is_snake_case(s)` must return `true`.
+    pub(crate) const unsafe fn from_str_unchecked(s: &str) -> &SnakeStr {
+        // `SnakeStr` is a `repr(transparent)` wrapper around `str`, so they have the same layout.
+        &*(s as *const str as *const SnakeStr)
+    }
+
+    pub const fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub const fn as_snake_case_ref(&self) -> SnakeCaseRef<'_> {
+        SnakeCaseRef(&self.0)
+    }
+
+    /// The underscore-separated words, skipping the empty ones from leading, trailing or consecutive underscores.
+    ///
+    /// ```
+    /// # use snake_case::SnakeCase;
+    /// let id = SnakeCase::try_from_str("_http__server_2").unwrap();
+    /// assert_eq!(id.words().collect::<Vec<_>>(), ["http", "server", "2"]);
+    /// assert_eq!(id.word_count(), 3);
+    /// ```
+    pub fn words(&self) -> impl DoubleEndedIterator<Item = &str> {
+        self.0.split('_').filter(|word| !word.is_empty())
+    }
+
+    /// The number of [`Self::words`]. Zero for strings with only underscores.
+    pub fn word_count(&self) -> usize {
+        self.words().count()
+    }
+
+    /// Does this start with the given words?
+    ///
+    /// Unlike [`str::starts_with`] this respects word boundaries, so `app_config` starts with `app`,
+    /// but `application` does not.
+    pub fn starts_with(&self, prefix: impl AsRef<SnakeStr>) -> bool {
+        let (s, prefix) = (self.as_str(), prefix.as_ref().as_str());
+        s.starts_with(prefix)
+            && (s.len() == prefix.len()
+                || s.as_bytes()[prefix.len()] == b'_'
+                || prefix.ends_with('_'))
+    }
+
+    /// Does this end with the given words?
+    ///
+    /// Unlike [`str::ends_with`] this respects word boundaries, so `app_config` ends with `config`,
+    /// but `app_reconfig` does not.
+    pub fn ends_with(&self, suffix: impl AsRef<SnakeStr>) -> bool {
+        let (s, suffix) = (self.as_str(), suffix.as_ref().as_str());
+        s.ends_with(suffix)
+            && (s.len() == suffix.len()
+                || s.as_bytes()[s.len() - suffix.len() - 1] == b'_'
+                || suffix.starts_with('_'))
+    }
+
+    /// Remove the leading words, and the underscore after them.
+    ///
+    /// ```
+    /// # use snake_case::SnakeStr;
+    /// let key = SnakeStr::try_from_str("app_config_timeout").unwrap();
+    /// let prefix = SnakeStr::try_from_str("app_config").unwrap();
+    /// assert_eq!(key.strip_prefix(prefix).unwrap(), "timeout");
+    /// assert_eq!(key.strip_prefix(key), None);
+    /// ```
+    ///
+    /// Returns `None` if this doesn't [`Self::starts_with`] the prefix,
+    /// or if the remainder is not valid snake_case (empty, or starting with a digit).
+    pub fn strip_prefix(&self, prefix: impl AsRef<SnakeStr>) -> Option<SnakeCaseRef<'_>> {
+        let prefix = prefix.as_ref();
+        if !self.starts_with(prefix) {
+            return None;
+        }
+        let mut rest = &self.as_str()[prefix.as_str().len()..];
+        if !prefix.as_str().ends_with('_') {
+            rest = rest.strip_prefix('_').unwrap_or(rest);
+        }
+        SnakeCaseRef::try_from_str(rest).ok()
+    }
+
+    /// Remove the trailing words, and the underscore before them.
+    ///
+    /// ```
+    /// # use snake_case::SnakeStr;
+    /// let key = SnakeStr::try_from_str("app_config_timeout").unwrap();
+    /// let suffix = SnakeStr::try_from_str("timeout").unwrap();
+    /// assert_eq!(key.strip_suffix(suffix).unwrap(), "app_config");
+    /// ```
+    ///
+    /// Returns `None` if this doesn't [`Self::ends_with`] the suffix, or if nothing remains.
+    pub fn strip_suffix(&self, suffix: impl AsRef<SnakeStr>) -> Option<SnakeCaseRef<'_>> {
+        let suffix = suffix.as_ref();
+        if !self.ends_with(suffix) {
+            return None;
+        }
+        let s = self.as_str();
+        let mut rest = &s[..s.len() - suffix.as_str().len()];
+        if !suffix.as_str().starts_with('_') {
+            rest = rest.strip_suffix('_').unwrap_or(rest);
+        }
+        SnakeCaseRef::try_from_str(rest).ok()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToOwned for SnakeStr {
+    type Owned = SnakeCase;
+
+    fn to_owned(&self) -> SnakeCase {
+        SnakeCase(self.0.into())
+    }
+}
+
+impl AsRef<SnakeStr> for SnakeStr {
+    fn as_ref(&self) -> &SnakeStr {
+        self
+    }
+}
+
+/// All the read-only `str` methods work on snake_case too.
+///
+/// `starts_with`, `ends_with`, `strip_prefix` and `strip_suffix` are shadowed by the
+/// word-boundary versions on [`SnakeStr`]; use [`SnakeStr::as_str`] to get the `str` ones.
+impl Deref for SnakeStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for SnakeStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for SnakeStr {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl Borrow<str> for SnakeStr {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for SnakeStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for SnakeStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SnakeStr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl core::cmp::PartialEq<str> for SnakeStr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl core::cmp::PartialEq<&str> for SnakeStr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl core::cmp::PartialEq<SnakeStr> for str {
+    fn eq(&self, other: &SnakeStr) -> bool {
+        self == other.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::cmp::PartialEq<SnakeStr> for SnakeCase<S> {
+    fn eq(&self, other: &SnakeStr) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> core::cmp::PartialEq<&SnakeStr> for SnakeCase<S> {
+    fn eq(&self, other: &&SnakeStr) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+impl<S: SnakeCaseStorage> Deref for SnakeCase<S> {
+    type Target = SnakeStr;
+
+    fn deref(&self) -> &SnakeStr {
+        // SAFETY: `SnakeCase` is always valid snake_case,
+        // and `SnakeCaseStorage` always returns the same string it was validated with.
+        unsafe { SnakeStr::from_str_unchecked(self.as_str()) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: SnakeCaseStorage> Borrow<SnakeStr> for SnakeCase<S> {
+    fn borrow(&self) -> &SnakeStr {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: SnakeCaseStorage> AsRef<SnakeStr> for SnakeCase<S> {
+    fn as_ref(&self) -> &SnakeStr {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> AsRef<str> for SnakeCase<S> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> AsRef<[u8]> for SnakeCase<S> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&SnakeStr> for SnakeCase {
+    fn from(s: &SnakeStr) -> Self {
+        s.to_owned()
+    }
+}
+
+impl<'a> Deref for SnakeCaseRef<'a> {
+    type Target = SnakeStr;
+
+    fn deref(&self) -> &SnakeStr {
+        self.as_snake_str()
+    }
+}
+
+impl<'a> SnakeCaseRef<'a> {
+    pub const fn as_snake_str(&self) -> &'a SnakeStr {
+        // SAFETY: `SnakeCaseRef` is always valid snake_case.
+        unsafe { SnakeStr::from_str_unchecked(self.0) }
+    }
+
+    /// Like [`SnakeStr::strip_prefix`], but borrowing the original string rather than `self`.
+    pub fn strip_prefix(&self, prefix: impl AsRef<SnakeStr>) -> Option<SnakeCaseRef<'a>> {
+        self.as_snake_str().strip_prefix(prefix)
+    }
+
+    /// Like [`SnakeStr::strip_suffix`], but borrowing the original string rather than `self`.
+    pub fn strip_suffix(&self, suffix: impl AsRef<SnakeStr>) -> Option<SnakeCaseRef<'a>> {
+        self.as_snake_str().strip_suffix(suffix)
+    }
+}
+
+impl AsRef<SnakeStr> for SnakeCaseRef<'_> {
+    fn as_ref(&self) -> &SnakeStr {
+        self
+    }
+}
+
+impl AsRef<str> for SnakeCaseRef<'_> {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for SnakeCaseRef<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl<'a> From<&'a SnakeStr> for SnakeCaseRef<'a> {
+    fn from(s: &'a SnakeStr) -> Self {
+        s.as_snake_case_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn snake_str() {
+        assert!(SnakeStr::try_from_str("hello_world").is_ok());
+        assert!(SnakeStr::try_from_str("Hello").is_err());
+
+        let owned = SnakeCase::try_from_str("hello_world").unwrap();
+        let snake_str: &SnakeStr = &owned;
+        assert_eq!(snake_str, "hello_world");
+        assert_eq!(snake_str.to_owned(), owned);
+        assert_eq!(owned.as_ref().as_snake_str(), snake_str);
+    }
+
+    #[test]
+    fn str_api() {
+        fn takes_str(s: impl AsRef<str>) -> usize {
+            s.as_ref().len()
+        }
+        fn takes_bytes(b: impl AsRef<[u8]>) -> usize {
+            b.as_ref().len()
+        }
+
+        let owned = SnakeCase::try_from_str("hello_world").unwrap();
+        assert_eq!(owned.len(), 11);
+        assert!(owned.contains("o_w"));
+        assert_eq!(owned.split('_').count(), 2);
+        assert_eq!(&owned[..5], "hello");
+        assert_eq!(takes_str(&owned), 11);
+        assert_eq!(takes_bytes(owned.as_ref()), 11);
+
+        let borrowed = SnakeCaseRef::try_from_str("hello_world").unwrap();
+        assert_eq!(borrowed.find('w'), Some(6));
+        assert_eq!(takes_str(borrowed), 11);
+        assert_eq!(takes_bytes(borrowed), 11);
+    }
+
+    #[test]
+    fn words() {
+        let words = |s| {
+            let snake = SnakeStr::try_from_str(s).unwrap();
+            (snake.words().collect::<Vec<_>>(), snake.word_count())
+        };
+        assert_eq!(words("hello"), (vec!["hello"], 1));
+        assert_eq!(words("hello_world"), (vec!["hello", "world"], 2));
+        assert_eq!(words("__a__b__"), (vec!["a", "b"], 2));
+        assert_eq!(words("___"), (vec![], 0));
+    }
+
+    #[test]
+    fn prefix_and_suffix() {
+        let snake = |s| SnakeStr::try_from_str(s).unwrap();
+        let key = snake("app_config_timeout");
+
+        assert!(key.starts_with(snake("app")));
+        assert!(key.starts_with(snake("app_")));
+        assert!(key.starts_with(key));
+        assert!(!key.starts_with(snake("ap")));
+        assert!(key.ends_with(snake("timeout")));
+        assert!(key.ends_with(snake("_timeout")));
+        assert!(!key.ends_with(snake("out")));
+
+        assert_eq!(key.strip_prefix(snake("app")).unwrap(), "config_timeout");
+        assert_eq!(key.strip_prefix(snake("app_")).unwrap(), "config_timeout");
+        assert_eq!(key.strip_prefix(snake("ap")), None);
+        assert_eq!(key.strip_suffix(snake("config_timeout")).unwrap(), "app");
+        assert_eq!(key.strip_suffix(key), None);
+
+        assert_eq!(snake("app__x").strip_prefix(snake("app")).unwrap(), "_x");
+        assert_eq!(snake("app_2").strip_prefix(snake("app")), None);
+
+        let key_ref: SnakeCaseRef<'static> = SnakeCaseRef::try_from_str("app_timeout").unwrap();
+        let rest: SnakeCaseRef<'static> = key_ref.strip_prefix(snake("app")).unwrap();
+        assert_eq!(rest, "timeout");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_map_lookup_by_snake_str() {
+        use std::collections::HashMap;
+        let mut map: HashMap<SnakeCase, i32> = HashMap::new();
+        map.insert(SnakeCase::try_from_str("max_depth").unwrap(), 3);
+        let key = SnakeStr::try_from_str("max_depth").unwrap();
+        assert_eq!(map.get(key), Some(&3));
+    }
+}
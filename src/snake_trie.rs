@@ -0,0 +1,241 @@
+use alloc::{boxed::Box, collections::BTreeMap, vec, vec::Vec};
+use core::fmt;
+
+use crate::{SnakeCase, SnakeStr};
+
+/// A map from [`SnakeCase`] keys to values, indexed by the underscore-separated words of the keys.
+///
+/// Looking up `http_requests_total` walks three nodes instead of hashing the whole string,
+/// and finding everything under a prefix (or the longest stored prefix of a key) is cheap.
+///
+/// ```
+/// # use snake_case::{SnakeCase, SnakeTrie};
+/// let mut routes = SnakeTrie::new();
+/// for (name, handler) in [("http", 1), ("http_requests", 2), ("http_errors", 3), ("db", 4)] {
+///     routes.insert(SnakeCase::try_from_str(name).unwrap(), handler);
+/// }
+///
+/// assert_eq!(routes.get("http_errors"), Some(&3));
+/// assert_eq!(routes.iter_prefix("http").count(), 3);
+///
+/// let (prefix, handler) = routes.longest_prefix("http_requests_total").unwrap();
+/// assert_eq!((prefix.as_str(), *handler), ("http_requests", 2));
+/// ```
+///
+/// Prefixes are whole words: `http` is not a prefix of `https_requests`.
+/// Iteration is in word order, so a key comes before the longer keys under it.
+pub struct SnakeTrie<V> {
+    root: Node<V>,
+    len: usize,
+}
+
+struct Node<V> {
+    entry: Option<(SnakeCase, V)>,
+    children: BTreeMap<Box<str>, Node<V>>,
+}
+
+impl<V> Node<V> {
+    fn new() -> Self {
+        Self {
+            entry: None,
+            children: BTreeMap::new(),
+        }
+    }
+}
+
+/// Unlike [`SnakeStr::words`] this keeps the empty words, so `a_b` and `a__b` are different keys.
+fn words(key: &str) -> impl Iterator<Item = &str> {
+    key.split('_')
+}
+
+impl<V> SnakeTrie<V> {
+    pub fn new() -> Self {
+        Self {
+            root: Node::new(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the old value, if the key was already present.
+    pub fn insert(&mut self, key: SnakeCase, value: V) -> Option<V> {
+        let mut node = &mut self.root;
+        for word in words(key.as_str()) {
+            node = node.children.entry(word.into()).or_insert_with(Node::new);
+        }
+        let old = node.entry.replace((key, value)).map(|(_, value)| value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.node(key)?.entry.as_ref().map(|(_, value)| value)
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        let mut node = &mut self.root;
+        for word in words(key) {
+            node = node.children.get_mut(word)?;
+        }
+        node.entry.as_mut().map(|(_, value)| value)
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// The longest key that `key` starts with (in whole words), including `key` itself.
+    pub fn longest_prefix(&self, key: &str) -> Option<(&SnakeStr, &V)> {
+        let mut node = &self.root;
+        let mut longest = None;
+        for word in words(key) {
+            match node.children.get(word) {
+                Some(child) => node = child,
+                None => break,
+            }
+            if let Some(entry) = &node.entry {
+                longest = Some(entry);
+            }
+        }
+        longest.map(|(key, value)| (&**key, value))
+    }
+
+    pub fn iter(&self) -> SnakeTrieIter<'_, V> {
+        SnakeTrieIter {
+            stack: vec![&self.root],
+        }
+    }
+
+    /// All keys that start with the words of `prefix`, including `prefix` itself.
+    pub fn iter_prefix(&self, prefix: &str) -> SnakeTrieIter<'_, V> {
+        SnakeTrieIter {
+            stack: self.node(prefix).into_iter().collect(),
+        }
+    }
+
+    fn node(&self, key: &str) -> Option<&Node<V>> {
+        let mut node = &self.root;
+        for word in words(key) {
+            node = node.children.get(word)?;
+        }
+        Some(node)
+    }
+}
+
+impl<V> Default for SnakeTrie<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for SnakeTrie<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V> Extend<(SnakeCase, V)> for SnakeTrie<V> {
+    fn extend<I: IntoIterator<Item = (SnakeCase, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<V> core::iter::FromIterator<(SnakeCase, V)> for SnakeTrie<V> {
+    fn from_iter<I: IntoIterator<Item = (SnakeCase, V)>>(iter: I) -> Self {
+        let mut trie = Self::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+impl<'a, V> IntoIterator for &'a SnakeTrie<V> {
+    type Item = (&'a SnakeStr, &'a V);
+    type IntoIter = SnakeTrieIter<'a, V>;
+
+    fn into_iter(self) -> SnakeTrieIter<'a, V> {
+        self.iter()
+    }
+}
+
+/// See [`SnakeTrie::iter`] and [`SnakeTrie::iter_prefix`].
+pub struct SnakeTrieIter<'a, V> {
+    /// The nodes left to visit, the next one last.
+    stack: Vec<&'a Node<V>>,
+}
+
+impl<'a, V> Iterator for SnakeTrieIter<'a, V> {
+    type Item = (&'a SnakeStr, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            self.stack.extend(node.children.values().rev());
+            if let Some((key, value)) = &node.entry {
+                return Some((&**key, value));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+
+    fn trie(keys: &[&str]) -> SnakeTrie<usize> {
+        keys.iter()
+            .enumerate()
+            .map(|(i, key)| (SnakeCase::try_from_str(key).unwrap(), i))
+            .collect()
+    }
+
+    fn keys<'a>(iter: impl Iterator<Item = (&'a SnakeStr, &'a usize)>) -> Vec<&'a str> {
+        iter.map(|(key, _)| key.as_str()).collect()
+    }
+
+    #[test]
+    fn trie_basics() {
+        let mut trie = trie(&["a_b", "a__b", "a", "a_b_c", "b", "_a"]);
+        assert_eq!(trie.len(), 6);
+        assert_eq!(trie.get("a__b"), Some(&1));
+        assert_eq!(trie.get("a_"), None);
+        assert_eq!(keys(trie.iter()), ["_a", "a", "a__b", "a_b", "a_b_c", "b"]);
+        assert_eq!(keys(trie.iter_prefix("a_b")), ["a_b", "a_b_c"]);
+        assert_eq!(keys(trie.iter_prefix("c")), Vec::<&str>::new());
+
+        assert_eq!(
+            trie.insert(SnakeCase::try_from_str("b").unwrap(), 10),
+            Some(4)
+        );
+        *trie.get_mut("a").unwrap() += 100;
+        assert_eq!(trie.len(), 6);
+        assert_eq!(
+            format!("{:?}", trie.iter_prefix("b").next()),
+            r#"Some(("b", 10))"#
+        );
+        assert_eq!(trie.get("a"), Some(&102));
+    }
+
+    #[test]
+    fn longest_prefix() {
+        let trie = trie(&["http", "http_requests", "db_pool"]);
+        let longest = |key| trie.longest_prefix(key).map(|(key, _)| key.as_str());
+        assert_eq!(longest("http_requests_total"), Some("http_requests"));
+        assert_eq!(longest("http_errors"), Some("http"));
+        assert_eq!(longest("http"), Some("http"));
+        assert_eq!(longest("https"), None);
+        assert_eq!(longest("db"), None);
+    }
+}
@@ -0,0 +1,118 @@
+use sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type};
+
+use crate::{SnakeCase, SnakeCaseRef};
+
+// ----------------------------------------------------------------------------
+// Stored as text, validated when decoded.
+
+impl<DB: Database> Type<DB> for SnakeCase
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for SnakeCase
+where
+    for<'a> &'a str: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <&str as Encode<'q, DB>>::encode_by_ref(&self.as_str(), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <&str as Encode<'q, DB>>::size_hint(&self.as_str())
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for SnakeCase
+where
+    String: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let string = <String as Decode<'r, DB>>::decode(value)?;
+        Ok(SnakeCase::try_from_string(string)?)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl<DB: Database> Type<DB> for SnakeCaseRef<'_>
+where
+    for<'a> &'a str: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <&str as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <&str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for SnakeCaseRef<'_>
+where
+    for<'a> &'a str: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <&str as Encode<'q, DB>>::encode_by_ref(&self.as_str(), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <&str as Encode<'q, DB>>::size_hint(&self.as_str())
+    }
+}
+
+/// Borrows the string from the row, without allocating.
+impl<'r, DB: Database> Decode<'r, DB> for SnakeCaseRef<'r>
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let string = <&'r str as Decode<'r, DB>>::decode(value)?;
+        Ok(SnakeCaseRef::try_from_str(string)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{Connection as _, Row as _};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn round_trip() {
+        let mut conn = sqlx::SqliteConnection::connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE things (name TEXT)")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        let name = SnakeCase::try_from_str("hello_world").unwrap();
+        sqlx::query("INSERT INTO things (name) VALUES (?1), ('Hello')")
+            .bind(&name)
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        let rows = sqlx::query("SELECT name FROM things")
+            .fetch_all(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(rows[0].try_get::<SnakeCase, _>(0).unwrap(), name);
+        assert_eq!(
+            rows[0].try_get::<SnakeCaseRef<'_>, _>(0).unwrap(),
+            "hello_world"
+        );
+        assert!(rows[1].try_get::<SnakeCase, _>(0).is_err());
+        assert!(rows[1].try_get::<SnakeCaseRef<'_>, _>(0).is_err());
+    }
+}
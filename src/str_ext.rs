@@ -0,0 +1,67 @@
+use crate::{InvalidSnakeCase, SnakeCaseRef};
+
+#[cfg(feature = "alloc")]
+use crate::SnakeCase;
+
+/// snake_case methods on `str`, for call sites that read left to right.
+///
+/// ```
+/// use snake_case::SnakeCaseStrExt as _;
+///
+/// assert!("user_id".is_snake_case());
+/// let headers: Vec<_> = "Id,UserName,user_id"
+///     .split(',')
+///     .filter_map(|h| h.as_snake_case().ok())
+///     .collect();
+/// assert_eq!(headers, ["user_id"]);
+/// ```
+pub trait SnakeCaseStrExt {
+    /// Same as [`crate::is_snake_case`].
+    fn is_snake_case(&self) -> bool;
+
+    /// Same as [`SnakeCaseRef::try_from_str`].
+    fn as_snake_case(&self) -> Result<SnakeCaseRef<'_>, InvalidSnakeCase>;
+
+    /// Like [`SnakeCase::from_any_case`], but never fails:
+    /// input without any ASCII letters or digits becomes `_`.
+    ///
+    /// ```
+    /// use snake_case::SnakeCaseStrExt as _;
+    ///
+    /// assert_eq!("HelloWorld".to_snake_case_lossy(), "hello_world");
+    /// assert_eq!("!?".to_snake_case_lossy(), "_");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_snake_case_lossy(&self) -> SnakeCase;
+}
+
+impl SnakeCaseStrExt for str {
+    fn is_snake_case(&self) -> bool {
+        crate::is_snake_case(self)
+    }
+
+    fn as_snake_case(&self) -> Result<SnakeCaseRef<'_>, InvalidSnakeCase> {
+        SnakeCaseRef::try_from_str(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_snake_case_lossy(&self) -> SnakeCase {
+        SnakeCase::from_any_case(self)
+            .unwrap_or_else(|_| SnakeCase::from_string_unchecked("_".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SnakeCaseStrExt as _;
+
+    #[test]
+    fn str_ext() {
+        assert!("a_b".is_snake_case());
+        assert!(!"a-b".is_snake_case());
+        assert_eq!("a_b".as_snake_case().unwrap(), "a_b");
+        assert!("".as_snake_case().is_err());
+        assert_eq!("Hello World".to_snake_case_lossy(), "hello_world");
+        assert_eq!("".to_snake_case_lossy(), "_");
+    }
+}
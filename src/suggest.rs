@@ -0,0 +1,100 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{convert, SnakeCaseRef};
+
+/// The candidate closest to `input`, if any is close enough to be a plausible typo.
+///
+/// For "unknown key `max_dpeth`, did you mean `max_depth`?" messages.
+///
+/// ```
+/// # use snake_case::{suggest, SnakeCaseRef};
+/// let keys = ["max_depth", "min_depth", "timeout"].map(SnakeCaseRef::from_str_or_panic);
+/// assert_eq!(suggest("max_dpeth", keys).unwrap(), "max_depth");
+/// assert_eq!(suggest("depth_max", keys).unwrap(), "max_depth");
+/// assert_eq!(suggest("maxDepth", keys).unwrap(), "max_depth");
+/// assert_eq!(suggest("retries", keys), None);
+/// ```
+///
+/// The input is converted to snake_case first, and the distance is the number of
+/// single-character edits (insertions, deletions, substitutions and swaps of neighbours),
+/// except that the same words in a different order only count as one edit.
+/// A candidate needs to be within one edit per three characters.
+/// Ties go to the first candidate.
+pub fn suggest<'a>(
+    input: &str,
+    candidates: impl IntoIterator<Item = SnakeCaseRef<'a>>,
+) -> Option<SnakeCaseRef<'a>> {
+    let input = convert::to_snake_case_string(input).unwrap_or_else(|| String::from(input));
+    let mut best: Option<(usize, SnakeCaseRef<'a>)> = None;
+    for candidate in candidates {
+        let distance = word_distance(&input, candidate.as_str());
+        let max_distance = (candidate.len() / 3).max(1);
+        if distance <= max_distance && !matches!(best, Some((best, _)) if best <= distance) {
+            best = Some((distance, candidate));
+        }
+    }
+    best.map(|(_, candidate)| candidate)
+}
+
+fn word_distance(a: &str, b: &str) -> usize {
+    let distance = edit_distance(a.as_bytes(), b.as_bytes());
+    if distance > 1 && a.len() == b.len() {
+        let mut a_words: Vec<&str> = a.split('_').collect();
+        let mut b_words: Vec<&str> = b.split('_').collect();
+        a_words.sort_unstable();
+        b_words.sort_unstable();
+        if a_words == b_words {
+            return 1;
+        }
+    }
+    distance
+}
+
+/// The optimal string alignment distance: Levenshtein, plus swapping two neighbours as one edit.
+fn edit_distance(a: &[u8], b: &[u8]) -> usize {
+    // Three rows of the full table: `i - 2`, `i - 1` and `i`.
+    let mut two_ago: Vec<usize> = Vec::new();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut row = Vec::with_capacity(b.len() + 1);
+    for i in 1..=a.len() {
+        row.clear();
+        row.push(i);
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (prev[j] + 1).min(row[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(two_ago[j - 2] + 1);
+            }
+            row.push(distance);
+        }
+        core::mem::swap(&mut two_ago, &mut prev);
+        core::mem::swap(&mut prev, &mut row);
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        assert_eq!(edit_distance(b"", b""), 0);
+        assert_eq!(edit_distance(b"abc", b""), 3);
+        assert_eq!(edit_distance(b"kitten", b"sitting"), 3);
+        assert_eq!(edit_distance(b"ab", b"ba"), 1);
+        assert_eq!(word_distance("db_app_host", "app_db_host"), 1);
+        assert!(word_distance("db_app_host", "app_db_hots") > 1);
+    }
+
+    #[test]
+    fn suggestions() {
+        let keys = ["id", "user_id", "user_name"].map(SnakeCaseRef::from_str_or_panic);
+        assert_eq!(suggest("user_id", keys).unwrap(), "user_id");
+        assert_eq!(suggest("UserNmae", keys).unwrap(), "user_name");
+        assert_eq!(suggest("di", keys).unwrap(), "id");
+        assert_eq!(suggest("x", keys), None);
+        assert_eq!(suggest("user", keys), None);
+        assert_eq!(suggest("anything", []), None);
+    }
+}
@@ -0,0 +1,120 @@
+//! Validating eight bytes at a time ("SIMD within a register").
+//!
+//! This can't be `const`, so the `const fn`s use the byte-by-byte [`crate::validate`] instead.
+
+#[cfg(feature = "alloc")]
+use crate::{validate, InvalidSnakeCase};
+
+const fn splat(b: u8) -> u64 {
+    u64::from_ne_bytes([b; 8])
+}
+
+const HIGH_BITS: u64 = splat(0x80);
+
+/// Are all eight bytes one of `[_a-z0-9]`?
+#[inline]
+fn all_snake_case_bytes(word: u64) -> bool {
+    if word & HIGH_BITS != 0 {
+        return false; // non-ASCII
+    }
+    // Every byte is < 0x80, so none of the additions below carry into the next byte.
+    // For each byte, the high bit of `x + (0x80 - lo)` is set iff `x >= lo`,
+    // and the high bit of `x + (0x7F - hi)` is set iff `x > hi`.
+    let in_range = |lo: u8, hi: u8| {
+        let ge_lo = word + splat(0x80 - lo);
+        let gt_hi = word + splat(0x7F - hi);
+        ge_lo & !gt_hi
+    };
+    let lower = in_range(b'a', b'z');
+    let digit = in_range(b'0', b'9');
+    // The high bit of `y + 0x7F` is set iff `y != 0`:
+    let underscore = !((word ^ splat(b'_')) + splat(0x7F));
+    (lower | digit | underscore) & HIGH_BITS == HIGH_BITS
+}
+
+/// Same as [`crate::is_snake_case`], but faster for long strings.
+///
+/// ```
+/// # use snake_case::is_snake_case_fast;
+/// assert!(is_snake_case_fast("a_rather_long_column_name_2"));
+/// assert!(!is_snake_case_fast("a_rather_long_column_Name_2"));
+/// ```
+///
+/// It is not a `const fn`, so use [`crate::is_snake_case`] in `const` contexts.
+pub fn is_snake_case_fast(string: &str) -> bool {
+    let bytes = string.as_bytes();
+    match bytes.first() {
+        Some(&b) if b == b'_' || b.is_ascii_lowercase() => {}
+        _ => return false,
+    }
+
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in &mut chunks {
+        let mut word = [0; 8];
+        word.copy_from_slice(chunk);
+        if !all_snake_case_bytes(u64::from_ne_bytes(word)) {
+            return false;
+        }
+    }
+    chunks
+        .remainder()
+        .iter()
+        .all(|&b| b == b'_' || b.is_ascii_lowercase() || b.is_ascii_digit())
+}
+
+/// Same as [`validate`], but takes the fast path for valid strings.
+#[cfg(feature = "alloc")]
+pub(crate) fn validate_fast(string: &str) -> Result<(), InvalidSnakeCase> {
+    if is_snake_case_fast(string) {
+        Ok(())
+    } else {
+        validate(string) // find out what is wrong
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_bytes() {
+        for b in 0..=255_u8 {
+            let expected = b == b'_' || b.is_ascii_lowercase() || b.is_ascii_digit();
+            for i in 0..8 {
+                let mut word = *b"abcd_019";
+                word[i] = b;
+                assert_eq!(
+                    all_snake_case_bytes(u64::from_ne_bytes(word)),
+                    expected,
+                    "byte {:#x} at {}",
+                    b,
+                    i
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn same_as_is_snake_case() {
+        let strings = [
+            "",
+            "_",
+            "a",
+            "2",
+            "hello_world",
+            "hello_world_this_is_long",
+            "hello_world_this_is_lonG",
+            "Hello_world_this_is_long",
+            "hello_world_this\u{df}_long",
+            "hello_wo`ld_this_is_long",
+            "hello_wo{ld_this_is_long",
+            "hello_wo/ld_this_is_long",
+            "hello_wo:ld_this_is_long",
+            "hello_wo^ld_this_is_long",
+        ];
+        for s in strings {
+            assert_eq!(is_snake_case_fast(s), crate::is_snake_case(s), "{:?}", s);
+            assert_eq!(validate_fast(s), validate(s), "{:?}", s);
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use alloc::string::String;
+use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
+
+use unicode_ident::{is_xid_continue, is_xid_start};
+
+use crate::{InvalidSnakeCase, SnakeCase};
+
+/// Is this a non-empty snake_case string in any script, like `straße_länge`?
+///
+/// It must start with an underscore or a Unicode `XID_Start` character, followed by `XID_Continue` characters
+/// (which include digits and underscores), just like a Rust identifier. No character may be upper case.
+///
+/// ASCII snake_case is a subset of this.
+pub fn is_unicode_snake_case(s: &str) -> bool {
+    validate_unicode(s).is_ok()
+}
+
+fn validate_unicode(s: &str) -> Result<(), InvalidUnicodeSnakeCase> {
+    if s.is_empty() {
+        return Err(InvalidUnicodeSnakeCase::Empty);
+    }
+    for (offset, char) in s.char_indices() {
+        let valid = if offset == 0 {
+            char == '_' || is_xid_start(char)
+        } else {
+            is_xid_continue(char)
+        };
+        if !valid {
+            return Err(if offset == 0 {
+                InvalidUnicodeSnakeCase::InvalidStartChar { char, offset }
+            } else {
+                InvalidUnicodeSnakeCase::InvalidChar { char, offset }
+            });
+        }
+        // Catches title case too, like 'ǅ':
+        if !char.to_lowercase().eq(core::iter::once(char)) {
+            return Err(InvalidUnicodeSnakeCase::UppercaseChar { char, offset });
+        }
+    }
+    Ok(())
+}
+
+/// What is wrong with a string that is not [`UnicodeSnakeCase`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvalidUnicodeSnakeCase {
+    Empty,
+    InvalidStartChar { char: char, offset: usize },
+    InvalidChar { char: char, offset: usize },
+    UppercaseChar { char: char, offset: usize },
+}
+
+impl fmt::Display for InvalidUnicodeSnakeCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "expected snake_case, found an empty string"),
+            Self::InvalidStartChar { char, offset } => write!(
+                f,
+                "snake_case must start with a letter or underscore, found {:?} at offset {}",
+                char, offset
+            ),
+            Self::InvalidChar { char, offset } => write!(
+                f,
+                "invalid character {:?} at offset {}: snake_case may only contain letters, digits and underscores",
+                char, offset
+            ),
+            Self::UppercaseChar { char, offset } => write!(
+                f,
+                "upper case character {:?} at offset {}: snake_case must be lower case",
+                char, offset
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidUnicodeSnakeCase {}
+
+/// Like [`SnakeCase`], but in any script: `straße_länge`, `имя_файла`, `名前`.
+///
+/// See [`is_unicode_snake_case`] for the exact rules.
+/// The string is not normalized, so `é` written with a combining accent is different from a precomposed `é`.
+///
+/// ```
+/// # use snake_case::{SnakeCase, UnicodeSnakeCase};
+/// # use core::convert::TryFrom;
+/// let name = UnicodeSnakeCase::try_from_str("straße_länge").unwrap();
+/// assert_eq!(name.words().collect::<Vec<_>>(), ["straße", "länge"]);
+/// assert!(SnakeCase::try_from(name).is_err());
+///
+/// assert!(UnicodeSnakeCase::try_from_str("Straße").is_err());
+/// ```
+#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UnicodeSnakeCase(String);
+
+impl UnicodeSnakeCase {
+    pub fn try_from_str(s: &str) -> Result<Self, InvalidUnicodeSnakeCase> {
+        validate_unicode(s)?;
+        Ok(Self(s.into()))
+    }
+
+    pub fn try_from_string(s: String) -> Result<Self, InvalidUnicodeSnakeCase> {
+        validate_unicode(&s)?;
+        Ok(Self(s))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// The underscore-separated words, skipping the empty ones, like [`crate::SnakeStr::words`].
+    pub fn words(&self) -> impl DoubleEndedIterator<Item = &str> {
+        self.0.split('_').filter(|word| !word.is_empty())
+    }
+
+    /// Is this plain ASCII snake_case?
+    pub fn is_ascii(&self) -> bool {
+        self.0.is_ascii()
+    }
+}
+
+impl From<SnakeCase> for UnicodeSnakeCase {
+    fn from(snake: SnakeCase) -> Self {
+        Self(snake.into_string())
+    }
+}
+
+/// Fails unless it is all ASCII.
+impl TryFrom<UnicodeSnakeCase> for SnakeCase {
+    type Error = InvalidSnakeCase;
+
+    fn try_from(unicode: UnicodeSnakeCase) -> Result<Self, Self::Error> {
+        SnakeCase::try_from_string(unicode.0)
+    }
+}
+
+impl TryFrom<&str> for UnicodeSnakeCase {
+    type Error = InvalidUnicodeSnakeCase;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(s)
+    }
+}
+
+impl TryFrom<String> for UnicodeSnakeCase {
+    type Error = InvalidUnicodeSnakeCase;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from_string(s)
+    }
+}
+
+impl FromStr for UnicodeSnakeCase {
+    type Err = InvalidUnicodeSnakeCase;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl Deref for UnicodeSnakeCase {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for UnicodeSnakeCase {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::borrow::Borrow<str> for UnicodeSnakeCase {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for UnicodeSnakeCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for UnicodeSnakeCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl PartialEq<str> for UnicodeSnakeCase {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for UnicodeSnakeCase {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for UnicodeSnakeCase {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for UnicodeSnakeCase {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error as _;
+        let string = String::deserialize(deserializer)?;
+        Self::try_from_string(string).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unicode_snake_case() {
+        for valid in ["a", "_", "straße_länge", "имя_файла", "名前", "_x2", "ǆ"] {
+            assert!(is_unicode_snake_case(valid), "{:?}", valid);
+        }
+        assert_eq!(validate_unicode(""), Err(InvalidUnicodeSnakeCase::Empty));
+        assert_eq!(
+            validate_unicode("2a"),
+            Err(InvalidUnicodeSnakeCase::InvalidStartChar {
+                char: '2',
+                offset: 0
+            })
+        );
+        assert_eq!(
+            validate_unicode("ab-c"),
+            Err(InvalidUnicodeSnakeCase::InvalidChar {
+                char: '-',
+                offset: 2
+            })
+        );
+        assert_eq!(
+            validate_unicode("äÖ"),
+            Err(InvalidUnicodeSnakeCase::UppercaseChar {
+                char: 'Ö',
+                offset: 2
+            })
+        );
+        assert_eq!(
+            validate_unicode("ǅ"),
+            Err(InvalidUnicodeSnakeCase::UppercaseChar {
+                char: 'ǅ',
+                offset: 0
+            })
+        );
+
+        // Same as `is_snake_case` for ASCII:
+        for s in ["a_b", "_a1", "a-b", "A", "1", "a b"] {
+            assert_eq!(is_unicode_snake_case(s), crate::is_snake_case(s), "{:?}", s);
+        }
+
+        let ascii = UnicodeSnakeCase::from(SnakeCase::try_from_str("max_len").unwrap());
+        assert!(ascii.is_ascii());
+        assert_eq!(SnakeCase::try_from(ascii).unwrap(), "max_len");
+    }
+}
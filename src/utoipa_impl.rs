@@ -0,0 +1,61 @@
+use std::borrow::Cow;
+
+use utoipa::{
+    openapi::{
+        schema::{ObjectBuilder, Schema, Type},
+        RefOr,
+    },
+    PartialSchema, ToSchema,
+};
+
+use crate::{SnakeCase, SnakeCaseRef, SnakeStr};
+
+/// Like the `schemars` schema, with an example for the docs UI.
+fn snake_case_schema() -> RefOr<Schema> {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .pattern(Some("^[_a-z][_a-z0-9]*$"))
+        .examples(["user_id"])
+        .into()
+}
+
+macro_rules! impl_to_schema {
+    ($($ty:ty),*) => {
+        $(
+            impl PartialSchema for $ty {
+                fn schema() -> RefOr<Schema> {
+                    snake_case_schema()
+                }
+            }
+
+            impl ToSchema for $ty {
+                fn name() -> Cow<'static, str> {
+                    "SnakeCase".into()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_schema!(SnakeCase, SnakeCaseRef<'_>, SnakeStr);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_schema() {
+        let expected = serde_json::json!({
+            "type": "string",
+            "pattern": "^[_a-z][_a-z0-9]*$",
+            "examples": ["user_id"]
+        });
+        let schema = serde_json::to_value(SnakeCase::schema()).unwrap();
+        assert_eq!(schema, expected);
+        assert_eq!(
+            serde_json::to_value(SnakeCaseRef::schema()).unwrap(),
+            expected
+        );
+        assert_eq!(SnakeStr::name(), "SnakeCase");
+    }
+}
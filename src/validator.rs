@@ -0,0 +1,141 @@
+use crate::InvalidSnakeCase;
+
+/// Validates snake_case that arrives in chunks, without buffering it.
+///
+/// ```
+/// # use snake_case::{InvalidSnakeCase, SnakeCaseValidator};
+/// let mut validator = SnakeCaseValidator::new();
+/// validator.feed(b"hello_").unwrap();
+/// validator.feed(b"world").unwrap();
+/// validator.finish().unwrap();
+///
+/// let mut validator = SnakeCaseValidator::new();
+/// validator.feed(b"hello_").unwrap();
+/// assert_eq!(validator.feed(b"World"), Err(InvalidSnakeCase::UppercaseChar { offset: 6 }));
+/// ```
+///
+/// Offsets in the errors count from the start of the first chunk.
+/// Once an error has been found, every following call returns the same error.
+#[derive(Clone, Debug, Default)]
+pub struct SnakeCaseValidator {
+    len: usize,
+    error: Option<InvalidSnakeCase>,
+}
+
+impl SnakeCaseValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validate the next chunk.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<(), InvalidSnakeCase> {
+        if let Some(error) = &self.error {
+            return Err(error.clone());
+        }
+
+        if let Some(i) = crate::find_invalid_byte(chunk, self.len == 0) {
+            // If the character is split over two chunks, we can't tell what it is,
+            // and it is reported as `char::REPLACEMENT_CHARACTER`.
+            let error = crate::invalid_char(chunk, i, self.len + i);
+            self.error = Some(error.clone());
+            return Err(error);
+        }
+        self.len += chunk.len();
+        Ok(())
+    }
+
+    /// Number of valid bytes fed so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Fails with [`InvalidSnakeCase::Empty`] if nothing was fed, or with the error [`Self::feed`] found.
+    pub fn finish(self) -> Result<(), InvalidSnakeCase> {
+        match self.error {
+            Some(error) => Err(error),
+            None if self.len == 0 => Err(InvalidSnakeCase::Empty),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Feed it from an [`std::io::Read`] with [`std::io::copy`].
+///
+/// Invalid snake_case is reported as [`std::io::ErrorKind::InvalidData`].
+#[cfg(feature = "std")]
+impl std::io::Write for SnakeCaseValidator {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.feed(buf)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validator() {
+        let validate_chunks = |chunks: &[&[u8]]| {
+            let mut validator = SnakeCaseValidator::new();
+            for chunk in chunks {
+                validator.feed(chunk)?;
+            }
+            validator.finish()
+        };
+
+        assert_eq!(validate_chunks(&[b"a", b"", b"_b2"]), Ok(()));
+        assert_eq!(validate_chunks(&[]), Err(InvalidSnakeCase::Empty));
+        assert_eq!(validate_chunks(&[b""]), Err(InvalidSnakeCase::Empty));
+        assert_eq!(
+            validate_chunks(&[b"", b"2d"]),
+            Err(InvalidSnakeCase::InvalidStartChar {
+                char: '2',
+                offset: 0
+            })
+        );
+        assert_eq!(
+            validate_chunks(&[b"stra", "\u{df}e".as_bytes()]),
+            Err(InvalidSnakeCase::InvalidChar {
+                char: '\u{df}',
+                offset: 4
+            })
+        );
+        assert_eq!(
+            validate_chunks(&[b"stra", &"\u{df}".as_bytes()[..1]]),
+            Err(InvalidSnakeCase::InvalidChar {
+                char: char::REPLACEMENT_CHARACTER,
+                offset: 4
+            })
+        );
+
+        let mut validator = SnakeCaseValidator::new();
+        assert!(validator.feed(b"aB").is_err());
+        assert!(validator.feed(b"c").is_err());
+        assert_eq!(
+            validator.finish(),
+            Err(InvalidSnakeCase::UppercaseChar { offset: 1 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_reader() {
+        let mut validator = SnakeCaseValidator::new();
+        std::io::copy(&mut &b"hello_world"[..], &mut validator).unwrap();
+        assert_eq!(validator.len(), 11);
+        assert!(validator.finish().is_ok());
+
+        let mut validator = SnakeCaseValidator::new();
+        assert!(std::io::copy(&mut &b"Hello"[..], &mut validator).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+//! JavaScript bindings, so a web frontend can validate identifiers with the exact same rules as Rust.
+//!
+//! Exported with `wasm-bindgen` as:
+//!
+//! ```js
+//! isSnakeCase("user_id");          // true
+//! toSnakeCaseLossy("userId");      // "user_id"
+//!
+//! const name = new SnakeCase("user_id"); // throws an `Error` if it isn't snake_case
+//! SnakeCase.fromAnyCase("UserId").asStr; // "user_id"
+//! ```
+
+use alloc::string::{String, ToString as _};
+
+use wasm_bindgen::prelude::*;
+
+use crate::SnakeCaseStrExt as _;
+
+/// See [`crate::is_snake_case`].
+#[wasm_bindgen(js_name = isSnakeCase)]
+pub fn is_snake_case(string: &str) -> bool {
+    crate::is_snake_case(string)
+}
+
+/// See [`crate::SnakeCaseStrExt::to_snake_case_lossy`].
+#[wasm_bindgen(js_name = toSnakeCaseLossy)]
+pub fn to_snake_case_lossy(string: &str) -> String {
+    string.to_snake_case_lossy().into_string()
+}
+
+/// A [`crate::SnakeCase`], exported to JavaScript as `SnakeCase`.
+#[wasm_bindgen(js_name = SnakeCase)]
+#[derive(Clone, Debug)]
+pub struct SnakeCase(crate::SnakeCase);
+
+#[wasm_bindgen(js_class = SnakeCase)]
+impl SnakeCase {
+    /// Throws an `Error` with the message of [`crate::InvalidSnakeCase`] if the string isn't snake_case.
+    #[wasm_bindgen(constructor)]
+    pub fn new(string: &str) -> Result<SnakeCase, JsError> {
+        crate::SnakeCase::try_from_str(string)
+            .map(Self)
+            .map_err(|err| JsError::new(&err.to_string()))
+    }
+
+    /// See [`crate::SnakeCase::from_any_case`].
+    #[wasm_bindgen(js_name = fromAnyCase)]
+    pub fn from_any_case(string: &str) -> Result<SnakeCase, JsError> {
+        crate::SnakeCase::from_any_case(string)
+            .map(Self)
+            .map_err(|err| JsError::new(&err.to_string()))
+    }
+
+    #[wasm_bindgen(getter, js_name = asStr)]
+    pub fn as_str(&self) -> String {
+        self.0.as_str().into()
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.as_str()
+    }
+}
+
+impl From<SnakeCase> for crate::SnakeCase {
+    fn from(snake: SnakeCase) -> Self {
+        snake.0
+    }
+}
+
+impl From<crate::SnakeCase> for SnakeCase {
+    fn from(snake: crate::SnakeCase) -> Self {
+        Self(snake)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `JsError` only works on wasm32, so only the happy paths are tested here.
+    #[test]
+    fn wasm() {
+        assert!(is_snake_case("user_id"));
+        assert!(!is_snake_case("userId"));
+        assert_eq!(to_snake_case_lossy("userId"), "user_id");
+        assert_eq!(SnakeCase::new("user_id").unwrap().to_string(), "user_id");
+        assert_eq!(
+            SnakeCase::from_any_case("UserId").unwrap().as_str(),
+            "user_id"
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use alloc::string::String;
+use core::fmt;
+
+use crate::{InvalidSnakeCase, SnakeCase};
+
+/// A [`fmt::Write`] sink that only lets snake_case through, for building identifiers with `write!`.
+///
+/// By default, anything that would make the result invalid is rejected:
+/// the write fails, and [`Self::finish`] returns what went wrong.
+///
+/// ```
+/// # use snake_case::SnakeCaseWriter;
+/// use std::fmt::Write as _;
+///
+/// let mut writer = SnakeCaseWriter::new();
+/// write!(writer, "{}_{}", "table", 42).unwrap();
+/// assert_eq!(writer.finish().unwrap(), "table_42");
+///
+/// let mut writer = SnakeCaseWriter::new();
+/// assert!(write!(writer, "{}_{}", "Table", 42).is_err());
+/// assert!(writer.finish().is_err());
+/// ```
+///
+/// A [`Self::lossy`] writer converts instead, one character at a time:
+/// upper case letters are lower cased (with an underscore before them if they follow a lower case letter or digit),
+/// and runs of other characters become a single underscore between words.
+///
+/// ```
+/// # use snake_case::SnakeCaseWriter;
+/// use std::fmt::Write as _;
+///
+/// let mut writer = SnakeCaseWriter::lossy();
+/// write!(writer, "{}.{}", "userName", "Größe").unwrap();
+/// assert_eq!(writer.finish().unwrap(), "user_name_gr_e");
+/// ```
+///
+/// Since it never looks ahead, a lossy writer does not split acronyms: `HTTPServer` becomes `httpserver`.
+/// Use [`SnakeCase::from_any_case`] on the whole string for that.
+#[derive(Clone, Debug, Default)]
+pub struct SnakeCaseWriter {
+    string: String,
+    lossy: bool,
+    /// Converted characters since the last valid one, to be written as an underscore before the next one.
+    pending_separator: bool,
+    error: Option<InvalidSnakeCase>,
+}
+
+impl SnakeCaseWriter {
+    /// A writer that rejects invalid characters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A writer that converts invalid characters.
+    pub fn lossy() -> Self {
+        Self {
+            lossy: true,
+            ..Self::default()
+        }
+    }
+
+    /// Fails with the first problem if anything was rejected,
+    /// or with [`InvalidSnakeCase::Empty`] if nothing (valid) was written.
+    pub fn finish(self) -> Result<SnakeCase, InvalidSnakeCase> {
+        if let Some(err) = self.error {
+            Err(err)
+        } else if self.string.is_empty() {
+            Err(InvalidSnakeCase::Empty)
+        } else {
+            Ok(SnakeCase::from_string_unchecked(self.string))
+        }
+    }
+
+    fn write_strict(&mut self, s: &str) -> Result<(), InvalidSnakeCase> {
+        // Validate all of it first, so a failed write leaves nothing behind:
+        let bytes = s.as_bytes();
+        if let Some(i) = crate::find_invalid_byte(bytes, self.string.is_empty()) {
+            return Err(crate::invalid_char(bytes, i, self.string.len() + i));
+        }
+        self.string.push_str(s);
+        Ok(())
+    }
+
+    fn write_lossy(&mut self, s: &str) {
+        for c in s.chars() {
+            if c == '_' || c.is_ascii_lowercase() || c.is_ascii_digit() {
+                self.push_valid(c);
+            } else if c.is_ascii_uppercase() {
+                let last = self.string.as_bytes().last();
+                if matches!(last, Some(b) if b.is_ascii_lowercase() || b.is_ascii_digit()) {
+                    self.pending_separator = true;
+                }
+                self.push_valid(c.to_ascii_lowercase());
+            } else {
+                self.pending_separator = true;
+            }
+        }
+    }
+
+    fn push_valid(&mut self, c: char) {
+        let separator = self.pending_separator && !self.string.is_empty();
+        let leading_digit = self.string.is_empty() && c.is_ascii_digit();
+        if (separator && c != '_' && !self.string.ends_with('_')) || leading_digit {
+            self.string.push('_');
+        }
+        self.pending_separator = false;
+        self.string.push(c);
+    }
+}
+
+impl fmt::Write for SnakeCaseWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.error.is_some() {
+            return Err(fmt::Error);
+        }
+        if self.lossy {
+            self.write_lossy(s);
+            Ok(())
+        } else {
+            self.write_strict(s).map_err(|err| {
+                self.error = Some(err);
+                fmt::Error
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write as _;
+
+    use super::*;
+
+    #[test]
+    fn strict() {
+        let mut writer = SnakeCaseWriter::new();
+        write!(writer, "_{}", 2).unwrap();
+        assert_eq!(writer.clone().finish().unwrap(), "_2");
+        assert!(writer.write_str("a-b").is_err());
+        assert!(writer.write_str("c").is_err()); // stays failed
+        assert_eq!(
+            writer.finish().unwrap_err(),
+            InvalidSnakeCase::InvalidChar {
+                char: '-',
+                offset: 3
+            }
+        );
+
+        let mut writer = SnakeCaseWriter::new();
+        assert!(write!(writer, "{}", 2).is_err());
+        assert_eq!(
+            writer.finish().unwrap_err(),
+            InvalidSnakeCase::InvalidStartChar {
+                char: '2',
+                offset: 0
+            }
+        );
+        let mut writer = SnakeCaseWriter::new();
+        assert!(write!(writer, "gr\u{f6}\u{df}e").is_err());
+        assert_eq!(
+            writer.finish().unwrap_err(),
+            InvalidSnakeCase::InvalidChar {
+                char: '\u{f6}',
+                offset: 2
+            }
+        );
+        assert_eq!(
+            SnakeCaseWriter::new().finish().unwrap_err(),
+            InvalidSnakeCase::Empty
+        );
+    }
+
+    #[test]
+    fn lossy() {
+        let lossy = |parts: &[&str]| {
+            let mut writer = SnakeCaseWriter::lossy();
+            for part in parts {
+                writer.write_str(part).unwrap();
+            }
+            writer.finish()
+        };
+        assert_eq!(lossy(&["max", "Depth"]).unwrap(), "max_depth");
+        assert_eq!(lossy(&["3d", " ", "Model"]).unwrap(), "_3d_model");
+        assert_eq!(lossy(&["-a-", "-b-"]).unwrap(), "a_b");
+        assert_eq!(lossy(&["_a", "!", "_b"]).unwrap(), "_a_b");
+        assert_eq!(lossy(&["!?"]).unwrap_err(), InvalidSnakeCase::Empty);
+    }
+}